@@ -0,0 +1,90 @@
+//! Counts the heap allocations made while deriving many tinantas.
+//!
+//! Usage:
+//!
+//! ```text
+//! cargo bench --bench allocations
+//! ```
+//!
+//! We use a counting allocator instead of criterion because we care about the number of
+//! allocations per derivation, not just wall time.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use vidyut_prakriya::args::*;
+use vidyut_prakriya::Vyakarana;
+
+/// Wraps the system allocator and counts calls to `alloc` and `realloc`.
+struct CountingAllocator;
+
+static NUM_ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        NUM_ALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const NUM_DERIVATIONS: usize = 10_000;
+
+fn create_args() -> Vec<Tinanta> {
+    let dhatus = [
+        ("BU", Gana::Bhvadi),
+        ("qukf\\Y", Gana::Tanadi),
+        ("ga\\mx~", Gana::Bhvadi),
+        ("ci\\Y", Gana::Svadi),
+    ];
+
+    let mut ret = Vec::new();
+    for (upadesha, gana) in dhatus {
+        let dhatu = Dhatu::mula(Slp1String::from(upadesha).expect("ok"), gana);
+        for lakara in Lakara::iter() {
+            for purusha in Purusha::iter() {
+                for vacana in Vacana::iter() {
+                    let args = Tinanta::builder()
+                        .dhatu(dhatu.clone())
+                        .prayoga(Prayoga::Kartari)
+                        .purusha(purusha)
+                        .vacana(vacana)
+                        .lakara(lakara)
+                        .build()
+                        .expect("ok");
+                    ret.push(args);
+                }
+            }
+        }
+    }
+    ret
+}
+
+fn main() {
+    let v = Vyakarana::builder().log_steps(false).build();
+    let args = create_args();
+
+    let start_allocs = NUM_ALLOCS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut num_results = 0;
+    for i in 0..NUM_DERIVATIONS {
+        num_results += v.derive_tinantas(&args[i % args.len()]).len();
+    }
+    let duration = start.elapsed();
+    let num_allocs = NUM_ALLOCS.load(Ordering::Relaxed) - start_allocs;
+
+    println!(
+        "{NUM_DERIVATIONS} derivations ({num_results} results) in {duration:?}: {num_allocs} allocations ({:.1} per derivation)",
+        num_allocs as f64 / NUM_DERIVATIONS as f64
+    );
+}
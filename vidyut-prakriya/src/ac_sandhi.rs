@@ -98,6 +98,12 @@ pub fn apply_general_ac_sandhi(p: &mut Prakriya, i_start: usize, i_end: usize) {
         let eti_edhati = || t_y.has_adi(EN) && t_y.has_u_in(&["i\\R", "eDa~\\"]);
         let is_uth = || t_y.has_adi('U') && t_y.has_tag(T::FlagUth);
 
+        if EN.contains(x) && y == 'a' && ip.is_term_end(i_x) && ip.p.is_pada(i_x.i_term) {
+            // agne 'tra, vAyo 'tra
+            ip.run_for_char("6.1.109", &i_y, "");
+            return ip.update(i_x);
+        }
+
         if AK.contains(x) && al::is_savarna(x, y) {
             // HACK: ignore sandhi between upasarga and dhatu so that we can correctly derive prARinat,
             // etc.
@@ -400,12 +406,19 @@ pub fn run_antaranga(p: &mut Prakriya) -> Option<()> {
     Some(())
 }
 
-pub fn run_common(p: &mut Prakriya) -> Option<()> {
+/// Deletes an *apṛkta* "v" that remains from a pratyaya like kvip. (6.1.67)
+pub fn try_ver_aprktasya_lopa(p: &mut Prakriya) {
     for i in 0..p.terms().len() {
         if p.has(i, |t| t.is_pratyaya() && t.has_text("v")) {
             p.run_at("6.1.67", i, op::lopa);
         }
+    }
+}
+
+pub fn run_common(p: &mut Prakriya) -> Option<()> {
+    try_ver_aprktasya_lopa(p);
 
+    for i in 0..p.terms().len() {
         apply_ac_sandhi_at_term_boundary(p, i);
 
         if p.has(i, |t| t.has_text("div")) && p.is_pada(i) {
@@ -27,6 +27,7 @@ use crate::args::BaseKrt as K;
 use crate::args::Gana::*;
 use crate::args::Lakara::*;
 use crate::args::Sanadi as S;
+use crate::args::Stri;
 use crate::args::Taddhita as D;
 use crate::args::Upasarga as U;
 use crate::args::Vikarana as V;
@@ -158,6 +159,20 @@ fn try_pratyaya_adesha_at_index(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         // -tAt substitution needs to occur early because it conditions samprasarana.
         // N is to block pit-guNa, not for replacement of the last letter.
         op::optional_adesha("7.1.35", p, i_n, "tAta~N");
+    } else if p.is_chandasi() && n.is_tin() && n.has_tag(T::Bahuvacana) {
+        if n.has_text("ta") && n.has_tag(T::Madhyama) {
+            let is_lot = n.has_lakara(Lot);
+            // kfRutAt, ...
+            let done = is_lot && op::optional_adesha("7.1.44", p, i_n, "tAt");
+            if !done {
+                // aDattana, gacCatana, ...
+                // TODO: also support `tap`, `tanap`, and `Tana`, which need a multi-way choice.
+                op::optional_adesha("7.1.45", p, i_n, "tana");
+            }
+        } else if n.has_text("mas") && n.has_tag(T::Uttama) {
+            // emasi, ...
+            p.optional_run_at("7.1.46", i_n, |t| t.text.push('i'));
+        }
     }
 
     let anga = p.get(i_anga)?;
@@ -339,6 +354,26 @@ fn try_add_tuk_agama(p: &mut Prakriya) -> Option<()> {
     Some(())
 }
 
+/// Runs rules that change the `a` before a pratyaya's `k` to `i` when followed by Ap.
+///
+/// Example: kAraka + wAp -> kArikA
+fn try_ap_it_adesha(p: &mut Prakriya) -> Option<()> {
+    let i_ap = p.find_last_where(|t| t.is(Stri::wAp) || t.is(Stri::cAp) || t.is(Stri::qAp))?;
+    let i_prev = i_ap.checked_sub(1)?;
+    let prev = p.get(i_prev)?;
+
+    // "asupaH" blocks an Ap that follows a sup, even if the sup has been deleted, e.g. in
+    // bahuparivrAjakA.
+    if prev.is_pratyaya() && !prev.is_sup() && prev.ends_with("aka") {
+        p.run_at("7.3.44", i_prev, |t| {
+            let n = t.text.len();
+            t.text.replace_range(n - 3..n - 2, "i");
+        });
+    }
+
+    Some(())
+}
+
 /// Runs rules that change `cu~` to `ku~` in various contexts.
 ///
 /// (7.3.61 - 7.3.62)
@@ -1494,6 +1529,7 @@ pub fn run_before_dvitva(p: &mut Prakriya, is_lun: bool, skip_at_agama: bool) ->
 
 pub fn run_after_dvitva(p: &mut Prakriya) -> Option<()> {
     subanta::run(p);
+    try_ap_it_adesha(p);
 
     for i in 0..p.len() {
         asiddhavat::run_after_dvitva(p, i);
@@ -1518,6 +1554,10 @@ pub fn run_after_dvitva(p: &mut Prakriya) -> Option<()> {
 
     abhyasasya::run_for_sani_or_cani(p);
 
+    // Must come before 6.4.77 so that iyaN and uvaN see the vowel of the next pratyaya (SriyO,
+    // juvO, ...).
+    ac_sandhi::try_ver_aprktasya_lopa(p);
+
     for index in 0..p.len() {
         try_ato_dirgha(p, index);
         asiddhavat::run_final(p, index);
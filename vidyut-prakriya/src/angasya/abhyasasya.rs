@@ -134,10 +134,13 @@ fn try_abhyasa_lopa_and_dhatu_change_before_san(p: &mut Prakriya) -> Option<()>
 /// `i` is the index of an abhyasa.
 fn run_for_sani_or_cani_at_index(p: &mut Prakriya, i: usize) -> Option<()> {
     const SMR_DR: &[Aupadeshika] = &[
+        // The sutra doesn't specify a gana, so include all homonyms.
         Au::smf_u,
+        Au::smf_a,
         Au::dF,
         Au::YitvarA,
         Au::praTa_u,
+        Au::praTa_a,
         Au::mrada,
         Au::stFY,
         // TODO: include both spaS dhAtus?
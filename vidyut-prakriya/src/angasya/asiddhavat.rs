@@ -26,6 +26,7 @@ use crate::core::operators as op;
 use crate::core::Rule::Varttika;
 use crate::core::{Morph, Prakriya, PrakriyaTag as PT, Rule, Tag as T, Term};
 use crate::dhatu_gana as gana;
+use crate::ganapatha;
 use crate::it_samjna;
 use crate::sounds as al;
 use crate::sounds::{s, Set};
@@ -331,10 +332,6 @@ fn try_run_kniti_sarvadhatuke(p: &mut Prakriya, i: usize) -> Option<()> {
 /// Run rules that replace the dhatu's vowel with e and apply abhyasa-lopa.
 /// Example: `la + laB + e` -> `leBe`
 fn try_et_adesha_and_abhyasa_lopa_for_lit(p: &mut Prakriya, i: usize) -> Option<()> {
-    // Applies only for liw.
-    if !p.terms().last()?.has_lakara(Lit) {
-        return None;
-    }
     if i == 0 {
         return None;
     }
@@ -343,6 +340,12 @@ fn try_et_adesha_and_abhyasa_lopa_for_lit(p: &mut Prakriya, i: usize) -> Option<
     let abhyasa = p.get_if(i - 1, |t| t.is_abhyasa())?;
     let n = p.pratyaya(i + 1)?;
 
+    // Applies only for liw, including kvasu~ and kAnac, which replace liw and might be followed
+    // by a sup-pratyaya.
+    if !n.last().has_lakara(Lit) {
+        return None;
+    }
+
     let kniti = n.is_knit();
     let thali_seti = n.first().is_it_agama() && n.last().has_u("Tal");
     if !(kniti || thali_seti) {
@@ -426,7 +429,8 @@ pub fn run_before_guna(p: &mut Prakriya, i: usize) -> Option<()> {
         let anga = p.get(i)?;
         let n = p.view(i_n, i_p)?;
         let anidit_hal = !anga.has_tag(T::idit) && anga.has_antya(HAL);
-        let is_kniti = n.is_knit();
+        // A kit pratyaya deleted by 6.1.66 still conditions this rule by 1.1.62. (prAN, ...)
+        let is_kniti = n.is_knit() || p.has(i + 1, |t| t.is_krt() && t.is_empty() && t.is_knit());
 
         if anga.has_text_in(&["skand", "syand"]) && n.last().is(K::ktvA) {
             p.step("6.4.31");
@@ -755,7 +759,11 @@ fn run_for_final_i_or_u(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         if (anga.has_u("hu\\") || anga.is(V::Snu)) && n.last().is_sarvadhatuka() && is_asamyogapurva
         {
             p.run_at("6.4.87", i_anga, op::antya("v"));
-        } else if anga.is_dhatu() || anga.is(V::Snu) || anga.has_text("BrU") {
+        } else if anga.is_dhatu()
+            || anga.is(V::Snu)
+            || anga.has_text("BrU")
+            || (anga.is_ekac_dhatu_pratipadika() && !p.has_prev_non_empty(i_anga, |_| true))
+        {
             // Apnuvanti, ...
             p.run("6.4.77", |p| to_iy_uv(p, i_anga));
         } else {
@@ -926,6 +934,12 @@ pub fn try_bhasya_for_index(p: &mut Prakriya, i: usize) -> Option<()> {
     let bha_prati = p.nyapu_pratipadika(i)?;
     let next = p.get(i_next)?;
     let taddhita = next.is_taddhita();
+    // The "v" of kvin is deleted only later, by 6.1.67.
+    let i_anc = if bha.is_pratyaya() && bha.has_text("v") {
+        p.prev_not_empty(i).unwrap_or(i)
+    } else {
+        bha_prati.end_non_empty().unwrap_or(i)
+    };
 
     if next.is_any_taddhita(&[D::izWan, D::imanic, D::Iyasun]) || ishtavat {
         if bha.has_text_in(&["sTUla", "dUra", "yuvan", "hrasva", "kzipra", "kzudra"]) {
@@ -987,17 +1001,22 @@ pub fn try_bhasya_for_index(p: &mut Prakriya, i: usize) -> Option<()> {
         });
     } else if bha.has_text_in(&["Svan", "yuvan", "maGavan"]) && !taddhita {
         p.run_at("6.4.133", i, |t| t.find_and_replace_text("va", "u"));
-    } else if bha_prati.last_non_empty()?.has_text("ac") {
-        // daDIcaH, ...
+    } else if p.has(i_anc, |t| t.has_text("ac") && t.has_u("ancu~")) {
         let i_start = bha_prati.start();
-        let i_end = bha_prati.end_non_empty()?;
-        // HACK: also change previous 'y' to 'i', 'v' to 'u'
-        if p.has(i_start, |t| t.has_antya('y')) {
-            p.set(i_start, |t| t.set_antya("i"));
-        } else if p.has(i_start, |t| t.has_antya('v')) {
-            p.set(i_start, |t| t.set_antya("u"));
+        let i_prev = p.prev_not_empty(i_anc).unwrap_or(i_anc);
+        if i_prev != i_anc && p.has(i_prev, |t| t.has_text("ud") && t.is_upasarga()) {
+            // udIcaH, ...
+            p.run_at("6.4.139", i_anc, op::text("Ic"));
+        } else {
+            // daDIcaH, pratIcaH, ...
+            // HACK: also change previous 'y' to 'i', 'v' to 'u'
+            if p.has(i_start, |t| t.has_antya('y')) {
+                p.set(i_start, |t| t.set_antya("i"));
+            } else if p.has(i_start, |t| t.has_antya('v')) {
+                p.set(i_start, |t| t.set_antya("u"));
+            }
+            p.run_at("6.4.138", i_anc, |t| t.set_adi(""));
         }
-        p.run_at("6.4.138", i_end, |t| t.set_adi(""));
     } else if bha.has_antya('n') {
         let mut block_lopa = false;
         if taddhita {
@@ -1022,6 +1041,10 @@ pub fn try_bhasya_for_index(p: &mut Prakriya, i: usize) -> Option<()> {
                 "suparvan",
             ]) {
                 p.run_at(Varttika("6.4.144.1"), i, op::ti(""));
+            } else if next.is(D::Qak) && bha.has_u_in(ganapatha::KALYANI_ADI) {
+                // kAlyARineya, ...
+                //
+                // The inaN-Adesha of 4.1.126 would be pointless if it were removed by 6.4.144.
             } else if ani && bha.ends_with("in") {
                 let n = bha.len();
                 if bha.has_text_in(&["gATin", "vidaTin", "keSin", "gaRin", "paRin"]) {
@@ -284,7 +284,8 @@ fn try_vrddhi_adesha(p: &mut Prakriya, i_anga: usize, i_n: usize) -> Option<()>
     } else if anga.has_text("mfj") && n.last().is_knit() && n.has_adi(AC) && !n.last().is_krt() {
         // mfjanti, mArjanti, ...
         p.optional_run_at(Rule::Kaumudi("2473"), i_anga, |t| t.try_upadha_vrddhi());
-    } else if n.first().is_taddhita() {
+    } else if n.last().is_taddhita() {
+        // Check `last` so that we skip any intervening agamas, e.g. BrU + vu~k + Qak.
         try_taddhita_vrddhi(p, i_anga, i_n);
     } else {
         try_nnit_vrddhi(p, i_anga, i_n);
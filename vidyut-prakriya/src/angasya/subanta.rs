@@ -279,8 +279,12 @@ fn try_add_num_agama_to_anga(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     let napum = p.has_tag(PT::Napumsaka);
 
     let is_ugit = anga.has_tag_in(&[T::udit, T::fdit]);
-    let is_ac = i_anga > 0 && p.has(i_anga - 1, |t| t.has_u("ancu~"));
-    let is_sarva = sup.is_sarvanamasthana();
+    // > acām iti añcater luptanakārasya grahaṇam
+    // -- KV on 7.1.70
+    let is_ac = i_anga > 0 && p.has(i_anga - 1, |t| t.has_u("ancu~") && !t.has_upadha('n'));
+    // 1.1.63 (na lumatAngasya) blocks num if the sup has been deleted by luk, e.g. after an
+    // avyaya (Bojam, kartum).
+    let is_sarva = sup.is_sarvanamasthana() && !sup.has_tag(T::Luk);
 
     if (is_ugit && !anga.is_dhatu()) || is_ac {
         let shatr = anga.has_u("Satf~");
@@ -288,7 +292,10 @@ fn try_add_num_agama_to_anga(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         let i_prev = p.find_prev_where(i_anga, |t| !t.is_empty());
 
         if shatr && (p.has(i_anga + 1, |t| t.has_u("SI") || t.has_tag(T::Nadi))) && i_anga > 0 {
-            let aat = p.has(i_prev?, |t| t.has_antya('a') || t.has_antya('A'));
+            // 7.1.78 (nAByastAc CatuH) also blocks 7.1.80: daDatI, not *daDantI.
+            let aat = p.has(i_prev?, |t| {
+                (t.has_antya('a') || t.has_antya('A')) && !t.is_abhyasta()
+            });
             if aat
                 || p.has(i_anga - 1, |t| {
                     (t.is(V::Sap) || t.is(V::Syan) || t.is(V::Sa)) && !t.is_lupta()
@@ -600,10 +607,13 @@ fn try_anga_adesha_after_vibhakti_changes(p: &mut Prakriya) -> Option<()> {
                 t.find_and_replace_text("asm", "ma");
             });
         }
-    } else if anga.has_u("idam") && anga.has_antya('a') {
+    } else if anga.has_u("idam")
+        && (anga.has_antya('a') || (anga.starts_with("id") && anga.has_antya('n')))
+    {
+        // The final `n` is from num-Agama (7.1.72), which we apply before this rule.
         if sup.last().has_tag_in(&[T::V1, T::V2]) {
-            // imam
-            p.run_at("7.2.109", i, |t| t.set_text("ima"));
+            // imam, imAni
+            p.run_at("7.2.109", i, |t| t.find_and_replace_text("id", "im"));
         } else {
             // Other vibhaktis
             if sup.has_adi(HAL) {
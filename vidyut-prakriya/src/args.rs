@@ -38,6 +38,8 @@ pub use sup::*;
 pub use taddhita::*;
 pub use tin::*;
 pub use unadi::Unadi;
+#[cfg(feature = "lipi")]
+pub use vidyut_lipi::Scheme;
 
 /// Models a semantic condition that applies to the *prakriyā* as a whole.
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
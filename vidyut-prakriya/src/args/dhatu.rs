@@ -1,6 +1,8 @@
 use crate::args::Pratipadika;
 use crate::args::Slp1String;
 use crate::core::errors::{Error, Result};
+use crate::core::Prakriya;
+use crate::dhatu_karya;
 use crate::enum_boilerplate;
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -180,6 +182,36 @@ enum_boilerplate!(Sanadi, {
     san => "san",
 });
 
+/// Extra constraints on how the *sanādi pratyaya*s of a *dhātu* are derived.
+///
+/// Some of these constraints apply only where the grammar allows an option. For example, many
+/// roots optionally allow *iṭ*-Agama before *san* (e.g. `didevizati`, `dudyUzati`), but `paW`
+/// always takes *iṭ* (`pipaWizati`) and `BU` never does (`buBUzati`). If a constraint can't be
+/// satisfied, the derivation fails.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SanadiArgs {
+    san_it: Option<bool>,
+}
+
+impl SanadiArgs {
+    /// Creates a new `SanadiArgs` with no constraints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether *san* must (`Some(true)`) or must not (`Some(false)`) take *iṭ*-Agama.
+    pub fn san_it(&self) -> Option<bool> {
+        self.san_it
+    }
+
+    /// Sets whether *san* must take *iṭ*-Agama.
+    pub fn with_san_it(mut self, value: bool) -> Self {
+        self.san_it = Some(value);
+        self
+    }
+}
+
 /// A *dhātu* from the Dhatupatha.
 #[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -188,6 +220,7 @@ pub struct Muladhatu {
     gana: Gana,
     antargana: Option<Antargana>,
     sanadi: Vec<Sanadi>,
+    sanadi_args: SanadiArgs,
     prefixes: Vec<String>,
 }
 
@@ -199,6 +232,7 @@ impl Muladhatu {
             gana,
             antargana: None,
             sanadi: Vec::new(),
+            sanadi_args: SanadiArgs::new(),
             prefixes: Vec::new(),
         }
     }
@@ -224,6 +258,11 @@ impl Muladhatu {
         &self.sanadi
     }
 
+    /// Extra constraints on the *sanādi pratyaya*s.
+    pub fn sanadi_args(&self) -> SanadiArgs {
+        self.sanadi_args
+    }
+
     /// The prefixes to use with this .
     pub fn prefixes(&self) -> &[String] {
         &self.prefixes
@@ -239,6 +278,20 @@ impl Muladhatu {
         self.antargana = Some(antargana);
         self
     }
+
+    /// Returns the dhatu as the engine sees it after removing its *it* letters and svaras.
+    ///
+    /// This also applies the rules that act on the dhatu as soon as it is stated, such as
+    /// *ṣatva* (6.1.64), *ṇatva* (6.1.65), and *num-āgama* (7.1.58). Prefixes and *sanādi
+    /// pratyaya*s are ignored.
+    ///
+    /// Returns an error if the *aupadeśika* form has no text after *it-lopa*.
+    pub fn clean_text(&self) -> Result<String> {
+        let dhatu = Muladhatu::new(self.aupadeshika.clone(), self.gana);
+        let mut p = Prakriya::new();
+        dhatu_karya::run(&mut p, &dhatu)?;
+        Ok(p.text())
+    }
 }
 
 /// A *dhātu* created from a *subanta*.
@@ -505,6 +558,14 @@ impl Dhatu {
         }
     }
 
+    /// Extra constraints on the *sanādi pratyaya*s to use with this dhatu.
+    pub fn sanadi_args(&self) -> SanadiArgs {
+        match self {
+            Self::Mula(m) => m.sanadi_args(),
+            Self::Nama(_) => SanadiArgs::new(),
+        }
+    }
+
     /// The prefixes to use with the dhatu.
     pub fn prefixes(&self) -> &[String] {
         match self {
@@ -514,6 +575,27 @@ impl Dhatu {
     }
 
     /// The aupadeshika text for this dhatu, if defined.
+    ///
+    /// This is the exact string used to create the dhatu, including its *it* letters and svaras.
+    /// It is unchanged by `with_prefixes` and `with_sanadi`. For the root without its *it*
+    /// letters, use `clean_text`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let kr = Dhatu::builder()
+    ///     .aupadeshika("qukf\\Y")
+    ///     .gana(Gana::Tanadi)
+    ///     .build()?
+    ///     .with_sanadi(&[Sanadi::san])
+    ///     .with_prefixes(&["sam"]);
+    /// assert_eq!(kr.aupadeshika(), Some("qukf\\Y"));
+    /// assert_eq!(kr.clean_text(), Some("kf".to_string()));
+    /// # Ok::<(), Error>(())
+    /// ```
     pub fn aupadeshika(&self) -> Option<&str> {
         match self {
             Self::Mula(m) => Some(m.aupadeshika()),
@@ -537,6 +619,30 @@ impl Dhatu {
         }
     }
 
+    /// Returns the dhatu without its *it* letters and svaras, if it is a *mūla-dhātu*.
+    ///
+    /// For details, see [`Muladhatu::clean_text`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let kr = Dhatu::mula(Slp1String::from("qukf\\Y")?, Gana::Tanadi);
+    /// assert_eq!(kr.clean_text(), Some("kf".to_string()));
+    ///
+    /// let nI = Dhatu::mula(Slp1String::from("RI\\Y")?, Gana::Bhvadi);
+    /// assert_eq!(nI.clean_text(), Some("nI".to_string()));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn clean_text(&self) -> Option<String> {
+        match self {
+            Self::Mula(m) => m.clean_text().ok(),
+            _ => None,
+        }
+    }
+
     /// Sets the prefixes on the dhatu.
     pub fn with_prefixes(mut self, values: &[impl AsRef<str>]) -> Self {
         match self {
@@ -578,6 +684,7 @@ pub struct DhatuBuilder {
     gana: Option<Gana>,
     antargana: Option<Antargana>,
     sanadi: Vec<Sanadi>,
+    sanadi_args: SanadiArgs,
     prefixes: Vec<String>,
 }
 
@@ -597,7 +704,10 @@ impl DhatuBuilder {
         self
     }
 
-    /// Sets whether or not this
+    /// Sets the antargana of the dhatu.
+    ///
+    /// Use this for custom roots that should follow the rules of a specific antargana, e.g. to
+    /// block guna for a *kuṭādi* root.
     pub fn antargana(mut self, value: Antargana) -> Self {
         self.antargana = Some(value);
         self
@@ -618,6 +728,30 @@ impl DhatuBuilder {
         self
     }
 
+    /// Sets the *sanādi pratyaya* to add to the dhatu along with extra constraints on how they are
+    /// derived.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// // didevizati, but not dudyUzati
+    /// let didevisha = Dhatu::builder()
+    ///     .aupadeshika("divu~")
+    ///     .gana(Gana::Divadi)
+    ///     .sanadi_with_args(&[Sanadi::san], SanadiArgs::new().with_san_it(true))
+    ///     .build()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn sanadi_with_args(mut self, values: &[Sanadi], args: SanadiArgs) -> Self {
+        self.sanadi.clear();
+        self.sanadi.extend(values);
+        self.sanadi_args = args;
+        self
+    }
+
     /// Converts the arguments in this builder into a `Dhatu` struct.
     pub fn build(self) -> Result<Dhatu> {
         Ok(Dhatu::Mula(Muladhatu {
@@ -631,6 +765,7 @@ impl DhatuBuilder {
             },
             antargana: self.antargana,
             sanadi: self.sanadi,
+            sanadi_args: self.sanadi_args,
             prefixes: self.prefixes,
         }))
     }
@@ -270,7 +270,9 @@ internal_term!(Aupadeshika, {
     dF => "dF",
     mrada => "mrada~\\",
     praTa_u => "praTa~",
+    praTa_a => "praTa~\\",
     smf_u => "smf",
+    smf_a => "smf\\",
     spaSa_u => "spaSa~",
     spaSa_s => "spaSa~^",
     stFY => "stFY",
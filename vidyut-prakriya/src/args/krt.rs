@@ -390,6 +390,54 @@ impl BaseKrt {
     pub fn aupadeshika(&self) -> &'static str {
         self.as_str()
     }
+
+    /// Returns the meaning conditions that affect how this *pratyaya* is derived.
+    ///
+    /// Each [`KrtArtha`] here can be passed to [`KrdantaBuilder::artha`] to restrict a derivation
+    /// to the rules for that sense. Most *pratyaya*s have no such conditions and return an empty
+    /// slice.
+    ///
+    /// ```
+    /// # use vidyut_prakriya::args::*;
+    /// assert!(BaseKrt::GaY.arthas().contains(&KrtArtha::Bhava));
+    /// assert!(BaseKrt::kta.arthas().is_empty());
+    /// ```
+    pub fn arthas(&self) -> &'static [KrtArtha] {
+        use BaseKrt as K;
+        use KrtArtha::*;
+        match self {
+            K::GaY => &[Bhava, Samjna],
+            K::ap => &[Bhava, Murti, Desha],
+            K::ac | K::Ra => &[Bhava],
+            K::Ga | K::qa | K::qara | K::ika | K::ikavaka => &[Samjna],
+            K::Aluc
+            | K::Aru
+            | K::GinuR
+            | K::Gurac
+            | K::Uka
+            | K::ini
+            | K::izRuc
+            | K::klukan
+            | K::kmarac
+            | K::knu
+            | K::kru
+            | K::krukan
+            | K::ksnu
+            | K::kurac
+            | K::kvarap
+            | K::kvip
+            | K::najiN
+            | K::ra
+            | K::tfn
+            | K::u
+            | K::ukaY
+            | K::varac
+            | K::vuY
+            | K::yuc
+            | K::zAkan => &[TacchilaTaddharmaTatsadhukara],
+            _ => &[],
+        }
+    }
 }
 
 /// Models a *kṛt pratyaya*.
@@ -436,7 +484,7 @@ impl Krt {
     pub fn is_avyaya(&self) -> bool {
         use BaseKrt::*;
         match self {
-            Krt::Base(k) => !matches!(
+            Krt::Base(k) => matches!(
                 k,
                 tumun
                     | Ramul
@@ -459,7 +507,7 @@ impl Krt {
                     | tosun
                     | kasun
             ),
-            Krt::Unadi(_) => true,
+            Krt::Unadi(_) => false,
         }
     }
 
@@ -501,6 +549,15 @@ pub enum KrtArtha {
     Karta,
 }
 
+enum_boilerplate!(KrtArtha, {
+    TacchilaTaddharmaTatsadhukara => "TacchilaTaddharmaTatsadhukara",
+    Bhava => "Bhava",
+    Murti => "Murti",
+    Desha => "Desha",
+    Samjna => "Samjna",
+    Karta => "Karta",
+});
+
 /// The information required to derive a *kṛdanta*.
 #[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -541,6 +598,38 @@ impl Krdanta {
         }
     }
 
+    /// Creates a `Krdanta` from a pipe-separated spec string with the following fields:
+    ///
+    /// ```text
+    /// upadesha|code|sanadi|krt
+    /// ```
+    ///
+    /// For details on the first three fields, see `Tinanta::from_spec`. `krt` accepts the same
+    /// strings as `BaseKrt::from_str`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let args = Krdanta::from_spec("qukf\\Y|08.0010|san|tfc")?;
+    /// assert_eq!(args.dhatu().sanadi(), &[Sanadi::san]);
+    /// assert_eq!(args.krt(), Krt::Base(BaseKrt::tfc));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let fields: Vec<_> = spec.split('|').map(|x| x.trim()).collect();
+        let [upadesha, code, sanadi, krt] = fields[..] else {
+            return Err(Error::ParseError(format!(
+                "Krdanta spec `{spec}` should have 4 fields"
+            )));
+        };
+
+        let dhatu = crate::dhatupatha::create_dhatu_from_spec(upadesha, code, sanadi)?;
+        let krt: BaseKrt = krt.parse()?;
+        Krdanta::builder().dhatu(dhatu).krt(krt).build()
+    }
+
     /// Returns a new builder for this struct.
     pub fn builder() -> KrdantaBuilder {
         KrdantaBuilder::default()
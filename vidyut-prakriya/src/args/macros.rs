@@ -1,7 +1,10 @@
 /// Implements various boilerplate for our enums.
+///
+/// Each variant maps to a string, which `as_str` and `Display` return. A variant may also list
+/// aliases (`Variant => "name" | "alias"`), which `FromStr` accepts as well.
 #[macro_export]
 macro_rules! enum_boilerplate {
-    ($Enum:ident, { $( $variant:ident => $str:literal ),* $(,)? }) => {
+    ($Enum:ident, { $( $variant:ident => $str:literal $(| $alias:literal)* ),* $(,)? }) => {
         impl $Enum {
             /// Returns a simple human-readable string that represents this enum's value.
             pub fn as_str(&self) -> &'static str {
@@ -30,7 +33,7 @@ macro_rules! enum_boilerplate {
             fn from_str(value: &str) -> $crate::core::errors::Result<Self> {
                 let ret = match value {
                     $(
-                        $str => $Enum::$variant,
+                        $str $(| $alias)* => $Enum::$variant,
                     )*
                     _ => return Err($crate::core::errors::Error::enum_parse_error(value))
                 };
@@ -30,6 +30,18 @@ impl Pada {
     }
 }
 
+impl From<&str> for Pada {
+    fn from(s: &str) -> Self {
+        Self::from_text(s)
+    }
+}
+
+impl From<String> for Pada {
+    fn from(s: String) -> Self {
+        Self::Dummy(s)
+    }
+}
+
 impl From<Subanta> for Pada {
     fn from(s: Subanta) -> Self {
         Self::Subanta(s)
@@ -29,6 +29,11 @@ impl BasicPratipadika {
 ///
 /// A pratipadika is the base to which we add sup-pratyayas. Through this process, we create
 /// subantas (nominals), which are complete words.
+///
+/// `Krdanta`, `Taddhitanta`, and `Samasa` all convert into a `Pratipadika`, so the output of one
+/// derivation can be the base of another (dhatu → krdanta → taddhitanta → subanta) without
+/// losing the structure of the original derivation. If the base derivation has several results,
+/// use `with_require` to select a specific one.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Pratipadika {
@@ -80,6 +85,12 @@ impl TryFrom<&str> for Pratipadika {
     }
 }
 
+impl From<BasicPratipadika> for Pratipadika {
+    fn from(b: BasicPratipadika) -> Self {
+        Self::Basic(b)
+    }
+}
+
 impl From<&Pratipadika> for Pratipadika {
     fn from(p: &Pratipadika) -> Self {
         p.clone()
@@ -1,5 +1,7 @@
 use crate::core::errors::{Error, Result};
 use crate::sounds::{AC, AL};
+#[cfg(feature = "lipi")]
+use vidyut_lipi::{Lipika, Scheme};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -87,6 +89,41 @@ impl Slp1String {
         Self::from_inner(text.as_ref())
     }
 
+    /// Tries to initialize a new `Slp1String` from text in the given `scheme`.
+    ///
+    /// The text is transliterated to SLP1 then validated as in `from`. Anusvara variants (such
+    /// as IAST *ṁ*) are normalized to `M`, and accent marks are moved after any `~`.
+    ///
+    /// This function creates a new `Lipika` on each call, which is wasteful for bulk input. If
+    /// you're converting many strings, transliterate them with a shared `vidyut_lipi::Lipika`
+    /// then call `from` instead.
+    ///
+    /// Requires the `lipi` feature.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let bhu = Slp1String::from_scheme("भू", Scheme::Devanagari)?;
+    /// assert_eq!(bhu, Slp1String::from("BU")?);
+    ///
+    /// let bhu = Slp1String::from_scheme("bhū", Scheme::Iast)?;
+    /// assert_eq!(bhu, Slp1String::from("BU")?);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[cfg(feature = "lipi")]
+    pub fn from_scheme(text: impl AsRef<str>, scheme: Scheme) -> Result<Self> {
+        let mut lipika = Lipika::new();
+        let slp1 = lipika
+            .transliterate(text.as_ref(), scheme, Scheme::Slp1)
+            .replace('ṁ', "M")
+            .replace("\\~", "~\\")
+            .replace("^~", "~^");
+        Self::from_inner(&slp1)
+    }
+
     fn from_inner(text: &str) -> Result<Self> {
         let bytes = text.as_bytes();
         for (i, c) in text.char_indices() {
@@ -158,6 +195,12 @@ impl Slp1String {
     }
 }
 
+impl AsRef<str> for Slp1String {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<String> for Slp1String {
     type Error = Error;
     fn try_from(val: String) -> Result<Slp1String> {
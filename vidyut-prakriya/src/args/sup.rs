@@ -122,6 +122,34 @@ pub struct Subanta {
 
 impl Subanta {
     /// Creates a subanta.
+    ///
+    /// `pratipadika` can be any nominal stem regardless of how it was created: a `Pratipadika`,
+    /// a `Krdanta`, a `Taddhitanta`, or a `Samasa`. So callers can decline any stem through
+    /// `Vyakarana::derive_subantas` without tracking the stem's provenance:
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let kr = Dhatu::mula(Slp1String::from("qukf\\Y")?, Gana::Tanadi);
+    ///
+    /// let deva = Subanta::new(
+    ///     Pratipadika::basic(Slp1String::from("deva")?),
+    ///     Linga::Pum,
+    ///     Vibhakti::Prathama,
+    ///     Vacana::Eka,
+    /// );
+    /// assert_eq!(v.derive_subantas(&deva)[0].text(), "devaH");
+    ///
+    /// let kartr = Subanta::new(
+    ///     Krdanta::new(kr, BaseKrt::tfc),
+    ///     Linga::Pum,
+    ///     Vibhakti::Prathama,
+    ///     Vacana::Eka,
+    /// );
+    /// assert_eq!(v.derive_subantas(&kartr)[0].text(), "kartA");
+    /// # Ok::<(), Error>(())
+    /// ```
     pub fn new(
         pratipadika: impl Into<Pratipadika>,
         linga: Linga,
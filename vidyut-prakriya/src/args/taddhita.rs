@@ -166,6 +166,8 @@ pub enum Taddhita {
     qatarac,
     /// -atama
     qatamac,
+    /// -A
+    qAc,
     /// -pa
     qupac,
     /// -mat
@@ -194,6 +196,8 @@ pub enum Taddhita {
     Rini,
     /// -ya
     Rya,
+    /// -tAti
+    tAtil,
     /// -tama
     tamap,
     /// -taya
@@ -442,6 +446,7 @@ enum_boilerplate!(Taddhita, {
     qati => "qati",
     qatarac => "qatarac",
     qatamac => "qatamac",
+    qAc => "qAc",
     qupac => "qupac",
     qmatup => "qmatu~p",
     qyaR => "qyaR",
@@ -456,6 +461,7 @@ enum_boilerplate!(Taddhita, {
     Ra => "Ra",
     Rini => "Rini~",
     Rya => "Rya",
+    tAtil => "tAtil",
     tamap => "tamap",
     tayap => "tayap",
     tarap => "tarap",
@@ -562,6 +568,8 @@ pub enum TaddhitaArtha {
     TasyaApatyam,
     /// Patronymic lineage. (4.1.98)
     Gotra,
+    /// A descendant of a *gotra* whose elder is still living. (4.1.94)
+    Yuvan,
     /// A class. (4.1.161)
     Jatau,
     /// Country. (4.1.168)
@@ -844,6 +852,7 @@ impl TaddhitaArtha {
     pub fn is_type_of(&self, parent: Self) -> bool {
         match self {
             Self::Gotra => matches!(parent, Self::Gotra | Self::TasyaApatyam),
+            Self::Yuvan => matches!(parent, Self::Yuvan | Self::TasyaApatyam),
             _ => *self == parent,
         }
     }
@@ -863,9 +872,9 @@ impl Taddhitanta {
     /// Defines a simple `Taddhitanta`.
     ///
     /// For more options, use `Taddhitanta::builder()` instead.
-    pub fn new(pratipadika: Pratipadika, taddhita: Taddhita) -> Self {
+    pub fn new(pratipadika: impl Into<Pratipadika>, taddhita: Taddhita) -> Self {
         Self {
-            pratipadika,
+            pratipadika: pratipadika.into(),
             taddhita,
             artha: None,
             require: None,
@@ -965,9 +974,12 @@ mod tests {
         use TaddhitaArtha::*;
         // Child relationship --> true
         assert!(Gotra.is_type_of(TasyaApatyam));
+        assert!(Yuvan.is_type_of(TasyaApatyam));
         // Equality --> true
         assert!(TasyaApatyam.is_type_of(TasyaApatyam));
         // Parent relationship --> false
         assert!(!TasyaApatyam.is_type_of(Gotra));
+        // Sibling relationship --> false
+        assert!(!Yuvan.is_type_of(Gotra));
     }
 }
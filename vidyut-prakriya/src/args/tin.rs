@@ -19,7 +19,8 @@ pub enum Prayoga {
     /// Usage coreferent with the object, e.g. "The village *is gone to* by the horse."
     Karmani,
     /// Usage without a referent, e.g. "*There is motion* by the horse to the village."
-    /// *bhāve prayoga* generally produces the same forms as karmani prayoga.
+    /// *bhāve prayoga* generally produces the same forms as karmani prayoga, but it is used only
+    /// in the *prathama-puruṣa* and *ekavacana*.
     Bhave,
 }
 
@@ -126,21 +127,52 @@ pub enum Lakara {
     Lrn,
 }
 
+// `FromStr` also accepts the traditional names in SLP1. See `Lakara::as_slp1`.
 enum_boilerplate!(Lakara, {
-    Lat => "lat",
-    Lit => "lit",
-    Lut => "lut",
-    Lrt => "lrt",
-    Let => "let",
-    Lot => "lot",
-    Lan => "lan",
-    VidhiLin => "vidhi-lin",
-    AshirLin => "ashir-lin",
-    Lun => "lun",
-    Lrn => "lrn",
+    Lat => "lat" | "law",
+    Lit => "lit" | "liw",
+    Lut => "lut" | "luw",
+    Lrt => "lrt" | "lfw",
+    Let => "let" | "lew",
+    Lot => "lot" | "low",
+    Lan => "lan" | "laN",
+    VidhiLin => "vidhi-lin" | "viDiliN",
+    AshirLin => "ashir-lin" | "ASIrliN",
+    Lun => "lun" | "luN",
+    Lrn => "lrn" | "lfN",
 });
 
 impl Lakara {
+    /// Returns the traditional name of this *lakāra* in SLP1, e.g. `law` or `viDiliN`.
+    ///
+    /// `as_str` (and `Display`) return a readable ASCII name instead, e.g. `lat` or `vidhi-lin`.
+    /// `FromStr` accepts both names:
+    ///
+    /// ```
+    /// # use vidyut_prakriya::args::Lakara;
+    /// assert_eq!(Lakara::VidhiLin.as_slp1(), "viDiliN");
+    /// assert_eq!("viDiliN".parse::<Lakara>()?, Lakara::VidhiLin);
+    /// assert_eq!("vidhi-lin".parse::<Lakara>()?, Lakara::VidhiLin);
+    /// assert_eq!(Lakara::VidhiLin.to_string(), "vidhi-lin");
+    /// # Ok::<(), vidyut_prakriya::Error>(())
+    /// ```
+    pub fn as_slp1(&self) -> &'static str {
+        use Lakara::*;
+        match self {
+            Lat => "law",
+            Lit => "liw",
+            Lut => "luw",
+            Lrt => "lfw",
+            Let => "lew",
+            Lot => "low",
+            Lan => "laN",
+            VidhiLin => "viDiliN",
+            AshirLin => "ASIrliN",
+            Lun => "luN",
+            Lrn => "lfN",
+        }
+    }
+
     /// Returns the *aupadeśika* form of this *pratyaya*.
     pub fn aupadeshika(&self) -> &'static str {
         use Lakara::*;
@@ -300,6 +332,12 @@ impl Tinanta {
     ///
     /// If unset, the program will use whatever padas are allowed by the Atmanepada section of the
     /// Ashtadhyayi. See the `atmanepada` module for details.
+    ///
+    /// These rules already account for the dhatu's *sanādi pratyaya*s and for the `prayoga`. For
+    /// example, *karmaṇi* and *bhāve prayoga* always use *ātmanepada* (1.3.13), and a desiderative
+    /// of an *ātmanepadī* root is likewise *ātmanepadī* (1.3.62). So most callers can leave this
+    /// field unset. If set to a pada that the grammar does not allow, the derivation will return
+    /// no results.
     pub fn pada(&self) -> Option<DhatuPada> {
         self.pada
     }
@@ -310,6 +348,45 @@ impl Tinanta {
         self
     }
 
+    /// Creates a `Tinanta` from a pipe-separated spec string with the following fields:
+    ///
+    /// ```text
+    /// upadesha|code|sanadi|prayoga|lakara|purusha|vacana
+    /// ```
+    ///
+    /// Here, `code` is the dhatu's Dhatupatha code (e.g. `01.0001`), which we use to find the
+    /// dhatu's gana and antargana, and `sanadi` is a `+`-separated list of *sanādi pratyaya*s that
+    /// may be empty. All other fields accept the same strings as the `from_str` method of their
+    /// enum.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let args = Tinanta::from_spec("BU|01.0001||kartari|law|prathama|eka")?;
+    /// assert_eq!(args.dhatu().aupadeshika(), Some("BU"));
+    /// assert_eq!(args.lakara(), Lakara::Lat);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self, Error> {
+        let fields: Vec<_> = spec.split('|').map(|x| x.trim()).collect();
+        let [upadesha, code, sanadi, prayoga, lakara, purusha, vacana] = fields[..] else {
+            return Err(Error::ParseError(format!(
+                "Tinanta spec `{spec}` should have 7 fields"
+            )));
+        };
+
+        let dhatu = crate::dhatupatha::create_dhatu_from_spec(upadesha, code, sanadi)?;
+        Tinanta::builder()
+            .dhatu(dhatu)
+            .prayoga(prayoga.parse()?)
+            .lakara(lakara.parse()?)
+            .purusha(purusha.parse()?)
+            .vacana(vacana.parse()?)
+            .build()
+    }
+
     /// Returns a new builder for this struct.
     ///
     /// For details, see `TinantaArgsBuilder`.
@@ -379,6 +456,33 @@ impl TinantaArgsBuilder {
         self
     }
 
+    /// Sets the derivation to *karmaṇi prayoga* with a *karman* of the given purusha and vacana.
+    ///
+    /// In *karmaṇi prayoga*, the *tiṅ* ending agrees with the *karman* instead of the *kartṛ*. So
+    /// *ahaṃ nīye* ("I am led") uses the uttama purusha and the eka vacana, and *te nīyante*
+    /// ("they are led") uses the prathama purusha and the bahu vacana.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let ni = Dhatu::mula(Slp1String::from("RI\\Y")?, Gana::Bhvadi);
+    /// let args = Tinanta::builder()
+    ///     .dhatu(ni)
+    ///     .lakara(Lakara::Lat)
+    ///     .karman(Purusha::Uttama, Vacana::Eka)
+    ///     .build()?;
+    /// assert_eq!(args.prayoga(), Prayoga::Karmani);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn karman(mut self, purusha: Purusha, vacana: Vacana) -> Self {
+        self.prayoga = Some(Prayoga::Karmani);
+        self.purusha = Some(purusha);
+        self.vacana = Some(vacana);
+        self
+    }
+
     /// Sets whether or not to skip the `a` Agama in the derivation.
     pub fn skip_at_agama(mut self, val: bool) -> Self {
         self.skip_at_agama = val;
@@ -396,27 +500,35 @@ impl TinantaArgsBuilder {
 
     /// Converts the arguments in this builder into a `TinantaArgs` struct.
     ///
-    /// `build()` will fail if any args are missing.
+    /// In *karmaṇi* and *bhāve prayoga*, the purusha defaults to the prathama purusha (1.4.108).
+    /// In *bhāve prayoga*, there is no *karman* to agree with, so the vacana defaults to the eka
+    /// vacana.
+    ///
+    /// `build()` will fail if any other args are missing.
     pub fn build(self) -> Result<Tinanta, Error> {
+        let dhatu = match self.dhatu {
+            Some(x) => x,
+            _ => return Err(Error::missing_required_field("dhatu")),
+        };
+        let prayoga = match self.prayoga {
+            Some(x) => x,
+            _ => return Err(Error::missing_required_field("prayoga")),
+        };
         Ok(Tinanta {
-            dhatu: match self.dhatu {
-                Some(x) => x,
-                _ => return Err(Error::missing_required_field("dhatu")),
-            },
-            prayoga: match self.prayoga {
-                Some(x) => x,
-                _ => return Err(Error::missing_required_field("prayoga")),
-            },
-            purusha: match self.purusha {
-                Some(x) => x,
+            dhatu,
+            prayoga,
+            purusha: match (self.purusha, prayoga) {
+                (Some(x), _) => x,
+                (None, Prayoga::Karmani | Prayoga::Bhave) => Purusha::Prathama,
                 _ => return Err(Error::missing_required_field("purusha")),
             },
             lakara: match self.lakara {
                 Some(x) => x,
                 _ => return Err(Error::missing_required_field("lakara")),
             },
-            vacana: match self.vacana {
-                Some(x) => x,
+            vacana: match (self.vacana, prayoga) {
+                (Some(x), _) => x,
+                (None, Prayoga::Bhave) => Vacana::Eka,
                 _ => return Err(Error::missing_required_field("vacana")),
             },
             pada: self.pada,
@@ -27,15 +27,15 @@ use crate::ac_sandhi;
 use crate::angasya;
 use crate::ardhadhatuka;
 use crate::args::{
-    Artha, Dhatu, Krdanta, Krt, Lakara, Pada, Pratipadika, Prayoga, Samasa, Subanta, Sup,
-    Taddhitanta, Tinanta, Upasarga,
+    Agama, Artha, Dhatu, Krdanta, Krt, Lakara, Pada, Pratipadika, Prayoga, Purusha, Samasa, Sanadi,
+    Subanta, Sup, Taddhitanta, Tinanta, Upasarga, Vacana,
 };
 use crate::atidesha;
 use crate::atmanepada;
 use crate::caching::{calculate_hash, Cache};
 use crate::core::errors::*;
 use crate::core::prakriya_stack::PrakriyaStack;
-use crate::core::{Prakriya, PrakriyaTag as PT, Stage, Tag, Term};
+use crate::core::{LogSteps, Prakriya, PrakriyaTag as PT, Stage, Tag, Term};
 use crate::dhatu_karya;
 use crate::dvitva;
 use crate::it_agama;
@@ -111,8 +111,6 @@ enum CachedPrakriya {
 // Returns whether this prakriya and its args trigger the `saMscaNoH` condition, which affects
 // various dhatu substitutions.
 fn is_sani_or_cani(p: &mut Prakriya, dhatu: Option<&Dhatu>, is_lun: bool) -> bool {
-    use crate::args::Sanadi;
-
     // Check if the following pratyaya will be san or can, for 2.4.51 (णौ च सँश्चङोः)
     let is_sani = match dhatu {
         Some(Dhatu::Mula(d)) => d.sanadi().iter().any(|s| *s == Sanadi::san),
@@ -151,9 +149,9 @@ fn prepare_dhatu(p: &mut Prakriya, dhatu: &Dhatu, args: MainArgs) -> Result<()>
     CACHE.with_borrow_mut(|cache| {
         if let Some(val) = cache.read(&cache_key) {
             match val {
-                CP::Good(val) => *p = val.clone(),
+                CP::Good(val) => p.clone_from(val),
                 CP::Fail(val) => {
-                    *p = val.clone();
+                    p.clone_from(val);
                     cache_ret = Err(Error::Abort(p.rule_choices().to_vec()));
                 }
             }
@@ -188,7 +186,7 @@ fn prepare_dhatu_inner(p: &mut Prakriya, dhatu: &Dhatu, args: MainArgs) -> Resul
             dhatu_karya::try_add_prefixes(p, n.prefixes());
             sanadi::try_create_namadhatu(p, n);
             if !p.terms().last().expect("ok").is_dhatu() {
-                return Err(Error::Abort(p.rule_choices().to_vec()));
+                return Err(p.abort("could not create a nāmadhātu from this pratipadika"));
             }
         }
     }
@@ -212,13 +210,25 @@ fn prepare_dhatu_inner(p: &mut Prakriya, dhatu: &Dhatu, args: MainArgs) -> Resul
             }
 
             run_main_rules(p, Some(dhatu), args);
+
+            if let Some(san_it) = dhatu.sanadi_args().san_it() {
+                if *s == Sanadi::san {
+                    // it-Agama, if any, is inserted directly before san.
+                    let i_san = p.find_last_where(|t| t.is(Sanadi::san));
+                    let has_it =
+                        i_san.map_or(false, |i| i > 0 && p.has(i - 1, |t| t.is(Agama::iw)));
+                    if has_it != san_it {
+                        return Err(p.abort("san does not match the requested iṭ-Agama"));
+                    }
+                }
+            }
         }
     }
 
     p.debug("~~~~~~~~~~~~~~ </prepare-dhatu> ~~~~~~~~~~~~~~~~~~");
 
     // Defer tripadi until we add other pratyayas.
-    Ok(())
+    p.check_failure()
 }
 
 /// Adds the basic terms necessary to create a *kṛdanta*.
@@ -263,7 +273,7 @@ fn prepare_krdanta(p: &mut Prakriya, args: &Krdanta) -> Result<()> {
     };
 
     if !added {
-        return Err(Error::Abort(p.rule_choices().to_vec()));
+        return Err(p.abort("could not add this krt pratyaya to the dhatu"));
     }
 
     if args.upapada().is_some() {
@@ -292,9 +302,9 @@ fn prepare_pratipadika(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()
     CACHE.with_borrow_mut(|cache| {
         if let Some(payload) = cache.read(&cache_key) {
             match payload {
-                CP::Good(val) => *p = val.clone(),
+                CP::Good(val) => p.clone_from(val),
                 CP::Fail(val) => {
-                    *p = val.clone();
+                    p.clone_from(val);
                     cache_ret = Err(Error::Abort(p.rule_choices().to_vec()));
                 }
             }
@@ -318,25 +328,33 @@ fn prepare_pratipadika(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()
 fn prepare_pratipadika_inner(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()> {
     match pratipadika {
         Pratipadika::Krdanta(k) if k.require().is_some() => {
-            let mut stack = PrakriyaStack::new(false, false, false, false);
+            let mut stack = PrakriyaStack::new(LogSteps::None, false, false, false);
             stack.find_all(|p| derive_krdanta(p, k));
 
             let mut added = false;
             if let Some(s) = k.require() {
                 for temp_p in stack.prakriyas() {
                     if *s == temp_p.text() {
+                        let i_start = p.terms().len();
                         p.extend(temp_p.terms());
+                        // The dhatu of a derived krdanta is already complete, so don't apply
+                        // upadesha rules like 6.1.45 to it again (netra, not *nAtra).
+                        for i in i_start..p.terms().len() {
+                            if p.has(i, |t| t.is_dhatu()) {
+                                p.set(i, |t| t.add_tag(Tag::Complete));
+                            }
+                        }
                         added = true;
                         break;
                     }
                 }
             }
             if !added {
-                return Err(Error::Abort(p.rule_choices().to_vec()));
+                return Err(p.abort("no krdanta matches the required text"));
             }
         }
         Pratipadika::Taddhitanta(t) if t.require().is_some() => {
-            let mut stack = PrakriyaStack::new(false, false, false, false);
+            let mut stack = PrakriyaStack::new(LogSteps::None, false, false, false);
             stack.find_all(|p| derive_taddhitanta(p, t));
 
             let mut added = false;
@@ -350,7 +368,7 @@ fn prepare_pratipadika_inner(p: &mut Prakriya, pratipadika: &Pratipadika) -> Res
                 }
             }
             if !added {
-                return Err(Error::Abort(p.rule_choices().to_vec()));
+                return Err(p.abort("no taddhitanta matches the required text"));
             }
         }
         Pratipadika::Basic(basic) => pratipadika_karya::add_basic(p, basic),
@@ -374,14 +392,16 @@ fn prepare_taddhitanta(p: &mut Prakriya, args: &Taddhitanta) -> Result<()> {
     // If defined, set the meaning condition that this prakriya must follow.
     //
     // Set `artha` *after* `prepare_pratipadika` to avoid clobbering `artha` when dealing with
-    // nested taddhitantas.
-    if let Some(artha) = args.artha() {
-        p.set_artha(Artha::Taddhita(artha));
+    // nested taddhitantas. For the same reason, clear the artha of a nested taddhitanta so that
+    // it doesn't restrict the senses available to this one.
+    match args.artha() {
+        Some(artha) => p.set_artha(Artha::Taddhita(artha)),
+        None => p.clear_artha(),
     }
 
     let added = taddhita::run(p, taddhita);
     if !added {
-        return Err(Error::Abort(p.rule_choices().to_vec()));
+        return Err(p.abort("could not add this taddhita pratyaya to the pratipadika"));
     }
 
     angasya::run_before_stritva(p);
@@ -417,7 +437,7 @@ fn prepare_samasa(p: &mut Prakriya, args: &Samasa) -> Result<()> {
 
     let added = samasa::run(p, args);
     if !added {
-        return Err(Error::Abort(p.rule_choices().to_vec()));
+        return Err(p.abort("could not create a samasa from these padas"));
     }
 
     pratipadika_karya::run_napumsaka_rules(p);
@@ -584,8 +604,7 @@ fn run_main_rules(p: &mut Prakriya, dhatu_args: Option<&Dhatu>, args: MainArgs)
 }
 
 /// Derives a single dhatu from the given conditions.
-pub fn derive_dhatu(mut prakriya: Prakriya, dhatu: &Dhatu) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_dhatu(p: &mut Prakriya, dhatu: &Dhatu) -> Result<()> {
     prepare_dhatu(p, dhatu, MainArgs::dhatu_args(dhatu, false, None))?;
     run_main_rules(
         p,
@@ -599,17 +618,22 @@ pub fn derive_dhatu(mut prakriya: Prakriya, dhatu: &Dhatu) -> Result<Prakriya> {
     );
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Derives a single tinanta from the given conditions.
-pub fn derive_tinanta(mut prakriya: Prakriya, args: &Tinanta) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_tinanta(p: &mut Prakriya, args: &Tinanta) -> Result<()> {
     let prayoga = args.prayoga();
     let lakara = args.lakara();
     let purusha = args.purusha();
     let vacana = args.vacana();
 
+    // In bhAve prayoga, the tiN-pratyaya expresses the action itself, which has no person or
+    // number. So only the default prathama-puruSa (1.4.108) and ekavacana are used.
+    if prayoga == Prayoga::Bhave && (purusha != Purusha::Prathama || vacana != Vacana::Eka) {
+        return Err(p.abort("bhAve prayoga is used only in prathama-puruSa ekavacana"));
+    }
+
     // Prayogas other than kartari will never be sarvadhatuka, since yak-vikarana is not
     // sarvadhatuka.
     let is_ardhadhatuka = match prayoga {
@@ -626,6 +650,7 @@ pub fn derive_tinanta(mut prakriya: Prakriya, args: &Tinanta) -> Result<Prakriya
     p.add_tags(&[purusha.as_tag().into(), vacana.as_tag().into()]);
 
     add_lakara_and_decide_pada(p, lakara);
+    p.check_failure()?;
     tin_pratyaya::adesha(p, purusha, vacana);
     samjna::run(p);
     run_main_rules(
@@ -640,12 +665,14 @@ pub fn derive_tinanta(mut prakriya: Prakriya, args: &Tinanta) -> Result<Prakriya
     );
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Derives a single subanta from the given conditions.
-pub fn derive_subanta(mut prakriya: Prakriya, args: &Subanta) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_subanta(p: &mut Prakriya, args: &Subanta) -> Result<()> {
+    // The requested linga takes priority over the linganushasana, e.g. `netra` (napumsaka by
+    // default) in `netraH`.
+    p.add_tag(PT::FlagHasLinga);
     prepare_pratipadika(p, args.pratipadika())?;
 
     p.add_tag(args.linga().as_tag().into());
@@ -653,18 +680,18 @@ pub fn derive_subanta(mut prakriya: Prakriya, args: &Subanta) -> Result<Prakriya
 
     sup_karya::run(p, args.linga(), args.vibhakti(), args.vacana());
     samjna::run(p);
+    sup_karya::try_luk_after_avyaya(p);
 
     samasa::run_rules_for_avyayibhava(p);
 
     run_main_rules(p, None, MainArgs::default());
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Derives a single krdanta from the given conditions.
-pub fn derive_krdanta(mut prakriya: Prakriya, args: &Krdanta) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_krdanta(p: &mut Prakriya, args: &Krdanta) -> Result<()> {
     prepare_krdanta(p, args)?;
 
     let is_ardhadhatuka = p.terms().last().map_or(false, |t| t.is_ardhadhatuka());
@@ -678,23 +705,24 @@ pub fn derive_krdanta(mut prakriya: Prakriya, args: &Krdanta) -> Result<Prakriya
             skip_at_agama: false,
         },
     );
+    if let Some(i_krt) = p.find_last_where(|t| t.is_krt()) {
+        samjna::try_avyaya_rules(p, i_krt);
+    }
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
-pub fn derive_taddhitanta(mut prakriya: Prakriya, args: &Taddhitanta) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_taddhitanta(p: &mut Prakriya, args: &Taddhitanta) -> Result<()> {
     prepare_taddhitanta(p, args)?;
 
     run_main_rules(p, None, MainArgs::default());
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
-pub fn derive_stryanta(mut prakriya: Prakriya, pratipadika: &Pratipadika) -> Result<Prakriya> {
-    let p = &mut prakriya;
+pub fn derive_stryanta(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()> {
     prepare_pratipadika(p, pratipadika)?;
 
     p.add_tag(PT::Stri);
@@ -704,7 +732,7 @@ pub fn derive_stryanta(mut prakriya: Prakriya, pratipadika: &Pratipadika) -> Res
     run_main_rules(p, None, MainArgs::default());
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Creates a dummy sup-pratyaya.
@@ -728,10 +756,9 @@ fn make_sup_pratyaya(vibhakti: crate::args::Vibhakti) -> Term {
     su
 }
 
-pub fn derive_samasa(mut prakriya: Prakriya, args: &Samasa) -> Result<Prakriya> {
+pub fn derive_samasa(p: &mut Prakriya, args: &Samasa) -> Result<()> {
     use crate::args::SamasaType;
 
-    let p = &mut prakriya;
     prepare_samasa(p, args)?;
 
     if args.samasa_type() == SamasaType::Avyayibhava {
@@ -744,52 +771,100 @@ pub fn derive_samasa(mut prakriya: Prakriya, args: &Samasa) -> Result<Prakriya>
     run_main_rules(p, None, MainArgs::default());
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
-pub fn derive_vakya(mut prakriya: Prakriya, padas: &[Pada]) -> Result<Prakriya> {
-    for pada in padas {
-        match pada {
-            Pada::Subanta(s) => {
-                let mut stack = PrakriyaStack::new(false, false, false, false);
-                stack.find_all(|p| derive_subanta(p, s));
-
-                if let Some(p) = stack.prakriyas().first() {
-                    prakriya.extend(p.terms());
-                }
+/// Creates a single *pada* term from a word that has already been derived.
+///
+/// We keep only the morphology that *vākya* rules need. Rerunning the full derivation on the
+/// original terms would reapply rules like 7.3.102 that have already run.
+fn make_derived_pada(p: &Prakriya) -> Option<Term> {
+    let last = p.terms().last()?;
+    let is_dvivacana = last.has_tag(Tag::Dvivacana);
+
+    // Restore a final visarga to the sound it came from. If it came from `ru~`, use `s` so that
+    // 8.2.66 can apply again (rAmo gacCati). Otherwise, it came from `r` (dvAr asti, pitar asti).
+    //
+    // We join the term texts directly since `p.text()` also renders svaras.
+    let text: String = p.terms().iter().map(|t| t.text.as_str()).collect();
+    let text = match text.strip_suffix('H') {
+        Some(prefix) => {
+            let is_ru = p
+                .terms()
+                .iter()
+                .rev()
+                .find(|t| !t.is_empty())
+                .map_or(false, |t| t.has_tag(Tag::Ru));
+            if is_ru {
+                format!("{prefix}s")
+            } else {
+                format!("{prefix}r")
             }
-            Pada::Tinanta(t) => {
-                let mut stack = PrakriyaStack::new(false, false, false, false);
-                stack.find_all(|p| derive_tinanta(p, t));
+        }
+        None => text,
+    };
 
-                if let Some(p) = stack.prakriyas().first() {
-                    prakriya.extend(p.terms());
-                }
-            }
-            Pada::Dummy(s) => {
-                let mut pada = Term::make_upadesha(s);
-                pada.add_tags(&[Tag::Pada]);
-                prakriya.push(pada);
-            }
-            Pada::Nipata(s) => {
-                let mut pada = match s.parse::<Upasarga>() {
-                    Ok(u) => u.into(),
-                    _ => Term::make_upadesha(s),
-                };
-                pada.add_tags(&[Tag::Pada, Tag::Avyaya, Tag::Nipata]);
-                if pada.has_antya('N') || pada.has_antya('Y') {
-                    pada.set_antya("");
-                }
-                prakriya.push(pada);
+    let mut pada = Term::make_upadesha(&text);
+    pada.add_tag(Tag::Pada);
+    if is_dvivacana {
+        pada.add_tag(Tag::Dvivacana);
+    }
+    Some(pada)
+}
+
+/// Returns a *pada* term for each distinct result of `pada`.
+///
+/// `stack` is used to derive subantas and tinantas.
+pub fn prepare_vakya_padas(pada: &Pada, mut stack: PrakriyaStack) -> Vec<Term> {
+    let derived = match pada {
+        Pada::Subanta(s) => {
+            stack.find_all(|p| derive_subanta(p, s));
+            stack.prakriyas()
+        }
+        Pada::Tinanta(t) => {
+            stack.find_all(|p| derive_tinanta(p, t));
+            stack.prakriyas()
+        }
+        Pada::Dummy(s) => {
+            let mut pada = Term::make_upadesha(s);
+            pada.add_tags(&[Tag::Pada]);
+            return vec![pada];
+        }
+        Pada::Nipata(s) => {
+            let mut pada = match s.parse::<Upasarga>() {
+                Ok(u) => u.into(),
+                _ => Term::make_upadesha(s),
+            };
+            pada.add_tags(&[Tag::Pada, Tag::Avyaya, Tag::Nipata]);
+            if pada.has_antya('N') || pada.has_antya('Y') {
+                pada.set_antya("");
             }
+            return vec![pada];
+        }
+    };
+
+    let mut ret: Vec<Term> = Vec::new();
+    for pada in derived.iter().filter_map(make_derived_pada) {
+        if !ret.iter().any(|t| t.text == pada.text) {
+            ret.push(pada);
         }
     }
+    ret
+}
 
-    let p = &mut prakriya;
+/// Joins the given *pada*s with external sandhi.
+///
+/// Each pada should come from `prepare_vakya_padas`.
+pub fn derive_vakya(prakriya: &mut Prakriya, padas: &[Term]) -> Result<()> {
+    for pada in padas {
+        prakriya.push(pada.clone());
+    }
+
+    let p = prakriya;
     p.stage = Stage::Vakya;
     samjna::try_pragrhya_rules(p);
     run_main_rules(p, None, MainArgs::default());
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
@@ -385,7 +385,10 @@ pub fn run(p: &mut Prakriya) -> Option<()> {
     // Otherwise (e.g. for sanAdi dhatus), skip this check.
     let la = pp.p.terms().last()?;
     if la.lakara.is_some() {
-        debug_assert!(p.has_tag_in(&[PT::Parasmaipada, PT::Atmanepada]));
+        // A pada might be missing only if the user disabled the rule that would assign it.
+        debug_assert!(
+            p.has_tag_in(&[PT::Parasmaipada, PT::Atmanepada]) || p.failure().is_some()
+        );
     }
 
     Some(())
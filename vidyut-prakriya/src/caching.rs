@@ -1,8 +1,10 @@
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
-use rustc_hash::FxHasher;
+use rustc_hash::{FxHashMap, FxHasher};
+use std::collections::BTreeMap;
 
 /// A simple LRU hash.
+#[derive(Debug)]
 pub(crate) struct Cache<K: Eq + Debug, V: Debug> {
     items: Vec<(i32, K, V)>,
     max_capacity: usize,
@@ -74,6 +76,68 @@ impl<K: Eq + Debug, V: Debug> Cache<K, V> {
     }
 }
 
+/// An LRU cache backed by a hash map.
+///
+/// Unlike `Cache`, reads and writes take O(log n) time, so this cache is suitable for large
+/// capacities. Space is allocated only as items are added.
+#[derive(Debug)]
+pub(crate) struct LruCache<K: Eq + Hash + Clone, V> {
+    /// Maps each key to its timestamp and value.
+    items: FxHashMap<K, (u64, V)>,
+    /// Maps each timestamp to its key, so that we can find the least recently used key.
+    stamps: BTreeMap<u64, K>,
+    max_capacity: usize,
+    /// Used to timestamp items in the cache.
+    next_stamp: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Creates a new cache with at most `max_capacity` items.
+    pub fn new(max_capacity: usize) -> Self {
+        Self {
+            items: FxHashMap::default(),
+            stamps: BTreeMap::new(),
+            max_capacity,
+            next_stamp: 0,
+        }
+    }
+
+    /// Reads from the cache and marks `key` as recently used.
+    pub fn read(&mut self, key: &K) -> Option<&V> {
+        let (stamp, value) = self.items.get_mut(key)?;
+        self.next_stamp += 1;
+        let old_stamp = core::mem::replace(stamp, self.next_stamp);
+        let key = self.stamps.remove(&old_stamp).expect("present");
+        self.stamps.insert(self.next_stamp, key);
+        Some(value)
+    }
+
+    /// Writes to the cache, evicting the least recently used item if the cache is full.
+    pub fn write(&mut self, key: K, value: V) {
+        if self.max_capacity == 0 {
+            return;
+        }
+
+        self.next_stamp += 1;
+        if let Some((stamp, _)) = self.items.get(&key) {
+            self.stamps.remove(stamp);
+        } else if self.items.len() >= self.max_capacity {
+            if let Some(oldest) = self.stamps.keys().next().copied() {
+                let old_key = self.stamps.remove(&oldest).expect("present");
+                self.items.remove(&old_key);
+            }
+        }
+        self.stamps.insert(self.next_stamp, key.clone());
+        self.items.insert(key, (self.next_stamp, value));
+    }
+
+    /// Returns the number of items in the cache.
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
 pub(crate) fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut s = FxHasher::default();
     t.hash(&mut s);
@@ -115,4 +179,47 @@ mod tests {
         assert_eq!(c.read(&4), Some(&40));
         assert_eq!(c.read(&5), Some(&50));
     }
+
+    #[test]
+    fn lru_caching() {
+        let mut c: LruCache<u64, u64> = LruCache::new(3);
+
+        // Empty cache
+        assert_eq!(c.read(&1), None);
+
+        // Fill up cache
+        c.write(1, 10);
+        assert_eq!(c.read(&1), Some(&10));
+        c.write(2, 20);
+        assert_eq!(c.read(&2), Some(&20));
+        c.write(3, 30);
+        assert_eq!(c.read(&3), Some(&30));
+
+        // Cache eviction of 1.
+        c.write(4, 40);
+        assert_eq!(c.read(&1), None);
+
+        // Cache eviction of 3, since 2 is recently read.
+        assert_eq!(c.read(&2), Some(&20));
+        c.write(5, 50);
+        assert_eq!(c.read(&2), Some(&20));
+
+        // Overwriting a key doesn't evict anything.
+        c.write(5, 55);
+        assert_eq!(c.len(), 3);
+
+        // Final state.
+        assert_eq!(c.read(&1), None);
+        assert_eq!(c.read(&2), Some(&20));
+        assert_eq!(c.read(&3), None);
+        assert_eq!(c.read(&4), Some(&40));
+        assert_eq!(c.read(&5), Some(&55));
+    }
+
+    #[test]
+    fn lru_caching_with_huge_capacity() {
+        let mut c: LruCache<u64, u64> = LruCache::new(usize::MAX);
+        c.write(1, 10);
+        assert_eq!(c.read(&1), Some(&10));
+    }
 }
@@ -18,6 +18,7 @@ pub(crate) mod term_view;
 
 pub use errors::Error;
 pub use prakriya::*;
+pub use prakriya_stack::OptionalMode;
 pub use tag::*;
 pub use term::*;
 pub use term_view::*;
@@ -55,7 +55,11 @@ impl<'a> IndexPrakriya<'a> {
     pub fn iter(&mut self, func: impl Fn(&mut IndexPrakriya, &CharIndex) -> Option<CharIndex>) {
         let mut index = self.first();
         while let Some(idx) = index {
+            let before = self.snapshot_if_rules_disabled();
             index = func(self, &idx);
+            if self.made_no_progress(&idx, &index, before) {
+                index = self.next(&idx);
+            }
         }
     }
 
@@ -67,10 +71,37 @@ impl<'a> IndexPrakriya<'a> {
     pub fn iter_rev(&mut self, func: impl Fn(&mut IndexPrakriya, &CharIndex) -> Option<CharIndex>) {
         let mut index = self.last();
         while let Some(idx) = index {
+            let before = self.snapshot_if_rules_disabled();
             index = func(self, &idx);
+            if self.made_no_progress(&idx, &index, before) {
+                index = self.prev(&idx);
+            }
+        }
+    }
+
+    /// Returns the current text if the prakriya has disabled rules.
+    ///
+    /// Our iterators usually revisit an index after a rule changes the text there. But if that
+    /// rule was disabled, nothing changes, and we would loop forever. So if rules are disabled, we
+    /// save the text so that we can check for this case.
+    fn snapshot_if_rules_disabled(&self) -> Option<String> {
+        if self.p.has_disabled_rules() {
+            Some(self.p.text())
+        } else {
+            None
         }
     }
 
+    /// Returns whether `func` revisited `idx` without changing the text.
+    fn made_no_progress(
+        &self,
+        idx: &CharIndex,
+        next: &Option<CharIndex>,
+        before: Option<String>,
+    ) -> bool {
+        next.as_ref() == Some(idx) && before.map_or(false, |text| text == self.p.text())
+    }
+
     pub fn for_non_empty_terms(
         &mut self,
         operator: impl Fn(&mut IndexPrakriya, usize, usize) -> Option<usize>,
@@ -154,6 +185,10 @@ impl<'a> IndexPrakriya<'a> {
     }
 
     pub fn run(&mut self, rule: impl Into<Rule>, func: impl Fn(&mut IndexPrakriya)) -> bool {
+        let rule = rule.into();
+        if self.p.fail_if_disabled(rule) {
+            return false;
+        }
         func(self);
         self.p.step(rule);
         true
@@ -164,7 +164,7 @@ pub fn nipatana(sub: &str) -> impl Fn(&mut Prakriya) + '_ {
 
 /// Complex op
 pub fn adesha(rule: impl Into<Rule>, p: &mut Prakriya, i: usize, sub: &str) {
-    p.run_at(rule, i, |t| {
+    let ran = p.run_at(rule, i, |t| {
         t.add_tag(T::Adesha);
         t.set_u(sub);
         t.set_text(sub);
@@ -173,7 +173,9 @@ pub fn adesha(rule: impl Into<Rule>, p: &mut Prakriya, i: usize, sub: &str) {
             t.morph = Morph::None;
         }
     });
-    it_samjna::run(p, i).expect("should always succeed");
+    if ran {
+        it_samjna::run(p, i).expect("should always succeed");
+    }
 }
 
 pub fn optional_adesha(
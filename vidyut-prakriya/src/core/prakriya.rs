@@ -4,9 +4,10 @@ Manages the derivation state.
 Users interested in understanding this module should start by reading the comments on the
 `Prakriya` struct, which manages a derivation from start to finish.
 */
-use crate::args::Artha;
-use crate::core::{PrakriyaTag, PrakriyaTag as PT, Tag, Term, TermView};
-use crate::sounds::Set;
+use crate::args::{Artha, Linga};
+use crate::core::errors::{Error, Result};
+use crate::core::{PrakriyaTag, PrakriyaTag as PT, Samjna, Tag, Term, TermView};
+use crate::sounds::{self, Set, AL};
 use enumset::EnumSet;
 
 /// A simple string label for some rule in the grammar.
@@ -21,6 +22,19 @@ pub enum Decision {
     Decline,
 }
 
+impl Decision {
+    /// Returns the other option for this decision.
+    ///
+    /// Since each optional rule can be either accepted or declined, this is the option that the
+    /// derivation did not take.
+    pub fn opposite(&self) -> Decision {
+        match self {
+            Decision::Accept => Decision::Decline,
+            Decision::Decline => Decision::Accept,
+        }
+    }
+}
+
 /// A rule applied in the *prakriyā*.
 ///
 /// Most of a derivation's rules come directly from the Ashtadhyayi. But, some derivations use
@@ -75,6 +89,70 @@ impl Rule {
             Self::Kaumudi(x) => x,
         }
     }
+
+    /// The text this rule comes from.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::Ashtadhyayi(_) => "ashtadhyayi",
+            Self::Varttika(_) => "varttika",
+            Self::Dhatupatha(_) => "dhatupatha",
+            Self::Kashika(_) => "kashika",
+            Self::Linganushasana(_) => "linganushasanam",
+            Self::Kaumudi(_) => "kaumudi",
+            Self::Unadipatha(_) => "unadi",
+            Self::Phit(_) => "phit",
+        }
+    }
+
+    /// The (*adhyāya*, *pāda*, *sūtra*) numbers of this rule, if it is a sutra from the
+    /// Ashtadhyayi.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Rule;
+    /// assert_eq!(Rule::Ashtadhyayi("3.1.68").ashtadhyayi_parts(), Some((3, 1, 68)));
+    /// assert_eq!(Rule::Varttika("3.1.68.1").ashtadhyayi_parts(), None);
+    /// ```
+    pub fn ashtadhyayi_parts(&self) -> Option<(u8, u8, u16)> {
+        match self {
+            Self::Ashtadhyayi(code) => {
+                let mut parts = code.split('.');
+                let adhyaya = parts.next()?.parse().ok()?;
+                let pada = parts.next()?.parse().ok()?;
+                let sutra = parts.next()?.parse().ok()?;
+                match parts.next() {
+                    Some(_) => None,
+                    None => Some((adhyaya, pada, sutra)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The (*pāda*, *sūtra*) numbers of this rule, if it is a sutra from the Unadipatha.
+    ///
+    /// For sutras that we split into multiple parts (e.g. `1.42:2`), this returns the numbers of
+    /// the full sutra.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Rule;
+    /// assert_eq!(Rule::Unadipatha("1.1").unadipatha_parts(), Some((1, 1)));
+    /// assert_eq!(Rule::Unadipatha("1.42:2").unadipatha_parts(), Some((1, 42)));
+    /// assert_eq!(Rule::Ashtadhyayi("1.1.1").unadipatha_parts(), None);
+    /// ```
+    pub fn unadipatha_parts(&self) -> Option<(u8, u16)> {
+        match self {
+            Self::Unadipatha(code) => {
+                let code = code.split(':').next()?;
+                let (pada, sutra) = code.split_once('.')?;
+                Some((pada.parse().ok()?, sutra.parse().ok()?))
+            }
+            _ => None,
+        }
+    }
 }
 
 // Since Ashtadhyayi rules are by far the most common, assume by default that static strings refer
@@ -110,6 +188,31 @@ impl Step {
     }
 }
 
+/// A position at which two derivation histories differ. (See `Prakriya::diff`.)
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct StepDiff {
+    index: usize,
+    left: Option<Step>,
+    right: Option<Step>,
+}
+
+impl StepDiff {
+    /// The index of this step in both histories.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The step in the first history, or `None` if that history has already ended.
+    pub fn left(&self) -> Option<&Step> {
+        self.left.as_ref()
+    }
+
+    /// The step in the second history, or `None` if that history has already ended.
+    pub fn right(&self) -> Option<&Step> {
+        self.right.as_ref()
+    }
+}
+
 /// One of the terms in the derivation.
 #[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 pub struct StepTerm {
@@ -139,6 +242,17 @@ pub struct RuleChoice {
 }
 
 impl RuleChoice {
+    /// Creates a new `RuleChoice`.
+    ///
+    /// This is mainly useful with `VyakaranaBuilder::rule_choices`, which replays a fixed decision
+    /// for some optional rule.
+    pub fn new(rule: impl Into<Rule>, decision: Decision) -> Self {
+        Self {
+            rule: rule.into(),
+            decision,
+        }
+    }
+
     /// The rule for which we made a decision.
     pub fn rule(&self) -> Rule {
         self.rule
@@ -150,11 +264,98 @@ impl RuleChoice {
     }
 }
 
+/// A nominal stem created by a *kṛdanta* or *taddhitānta* derivation, before any *sup-pratyaya*
+/// has been added. (See `Prakriya::as_pratipadika_entry`.)
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PratipadikaEntry {
+    text: String,
+    antya_kind: char,
+    likely_linga: Option<Linga>,
+}
+
+impl PratipadikaEntry {
+    /// The text of the stem, e.g. `kfta`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The last sound of the stem, which determines how the stem is declined. For example, `kfta`
+    /// is an *a*-stem and has `antya_kind` `'a'`.
+    pub fn antya_kind(&self) -> char {
+        self.antya_kind
+    }
+
+    /// The linga that the grammar assigns to this stem, if any.
+    ///
+    /// This value is set only if a rule (e.g. from the *Liṅgānuśāsana*) fixes the stem's linga,
+    /// as for *pāka* (masculine) or *kṛti* (feminine). Stems that can take any linga, such as
+    /// *kṛta*, return `None`.
+    pub fn likely_linga(&self) -> Option<Linga> {
+        self.likely_linga
+    }
+}
+
+/// Returns the separator to write between two *pada*s of a *vākya*.
+///
+/// `right_text` is the text of `right` along with any preceding non-pada terms.
+fn pada_separator(left: &Term, right: &Term, right_text: &str) -> &'static str {
+    let left_text = left.text.as_str();
+    let left_u = left.u.as_deref().unwrap_or(left_text);
+    let right_u = right.u.as_deref().unwrap_or(right_text);
+
+    let ends_in_ac = |s: &str| matches!(s.chars().last(), Some(c) if sounds::is_ac(c));
+    let starts_with_ac = |s: &str| matches!(s.chars().next(), Some(c) if sounds::is_ac(c));
+
+    if right_u.starts_with('a')
+        && !right_text.starts_with('a')
+        && (left_text.ends_with('e') || left_text.ends_with('o'))
+    {
+        // te 'tra, rAmo 'tra (6.1.109)
+        " '"
+    } else if ends_in_ac(left_u)
+        && starts_with_ac(right_u)
+        && !(ends_in_ac(left_text) && starts_with_ac(right_text))
+    {
+        // nAsti, maDvari, ...
+        ""
+    } else {
+        " "
+    }
+}
+
+/// Controls how much of the derivation history a `Prakriya` records.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LogSteps {
+    /// Record nothing. `Prakriya::history` will be empty.
+    #[default]
+    None,
+    /// Record which rules were applied, but not the state of the derivation after each rule. So
+    /// each `Step` will have a `rule` but an empty `result`.
+    ///
+    /// This setting uses much less memory than `Full` and is useful for bulk jobs that need rule
+    /// provenance (e.g. rule coverage statistics) but not the full derivation.
+    RulesOnly,
+    /// Record each rule and the state of the derivation after that rule.
+    Full,
+}
+
+impl From<bool> for LogSteps {
+    /// Maps `true` to `Full` and `false` to `None`.
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Full
+        } else {
+            Self::None
+        }
+    }
+}
+
 /// Configuration options that affect how a `Prakriya` behaves during the derivation.
 #[derive(Clone, Default, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub(crate) struct Config {
     pub rule_choices: Vec<RuleChoice>,
-    pub log_steps: bool,
+    pub disabled_rules: Vec<Rule>,
+    pub log_steps: LogSteps,
     pub is_chandasi: bool,
     pub use_svaras: bool,
     pub nlp_mode: bool,
@@ -202,7 +403,7 @@ pub(crate) enum Stage {
 /// For example, we might want the derivation to use *chandasi* rules, or we might wish to block
 /// such rules. Or, we might want to skip history logging so that we can generate words more
 /// quickly.
-#[derive(Clone, Default, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Default, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Prakriya {
     terms: Vec<Term>,
     pub(crate) stage: Stage,
@@ -211,6 +412,34 @@ pub struct Prakriya {
     artha: Option<Artha>,
     config: Config,
     pub(crate) rule_choices: Vec<RuleChoice>,
+    failure: Option<String>,
+}
+
+// `Clone` is implemented manually so that `clone_from` can reuse our existing buffers.
+impl Clone for Prakriya {
+    fn clone(&self) -> Self {
+        Prakriya {
+            terms: self.terms.clone(),
+            stage: self.stage.clone(),
+            tags: self.tags,
+            history: self.history.clone(),
+            artha: self.artha,
+            config: self.config.clone(),
+            rule_choices: self.rule_choices.clone(),
+            failure: self.failure.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.terms.clone_from(&source.terms);
+        self.stage = source.stage.clone();
+        self.tags = source.tags;
+        self.history.clone_from(&source.history);
+        self.artha = source.artha;
+        self.config.clone_from(&source.config);
+        self.rule_choices.clone_from(&source.rule_choices);
+        self.failure.clone_from(&source.failure);
+    }
 }
 
 /// Public API
@@ -232,8 +461,117 @@ impl Prakriya {
         ret
     }
 
+    /// Like `text()`, but with `sep` inserted between the terms of the derivation.
+    ///
+    /// Terms that have become empty, e.g. by *lopa* or by merging with a neighboring term during
+    /// sandhi, are skipped so that `sep` never appears twice in a row.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let args = Tinanta::builder()
+    ///     .dhatu(bhu)
+    ///     .prayoga(Prayoga::Kartari)
+    ///     .purusha(Purusha::Prathama)
+    ///     .vacana(Vacana::Eka)
+    ///     .lakara(Lakara::Lat)
+    ///     .build()?;
+    /// let prakriyas = v.derive_tinantas(&args);
+    /// assert_eq!(prakriyas[0].text_with_boundaries("-"), "Bav-a-ti");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn text_with_boundaries(&self, sep: &str) -> String {
+        let mut ret = String::from("");
+        for t in self.terms.iter().filter(|t| !t.text.is_empty()) {
+            if !ret.is_empty() {
+                ret.push_str(sep);
+            }
+            if self.config.use_svaras {
+                ret.push_str(&t.text_with_svaras().replace('\\', ""));
+            } else {
+                ret.push_str(&t.text);
+            }
+        }
+        ret
+    }
+
+    /// Like `text()`, but with the *pada*s of a *vākya* separated by spaces.
+    ///
+    /// Padas that were merged by vowel sandhi are written together, as in `nAsti`. If a pada lost
+    /// its initial `a` by 6.1.109, we write an avagraha (`'`) in its place, as in `te 'tra`. For
+    /// derivations that aren't from [`Vyakarana::derive_vakyas`](crate::Vyakarana::derive_vakyas),
+    /// this returns the same value as `text()`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let v = Vyakarana::new();
+    /// let padas = [Pada::from_text("rAmas"), Pada::from_text("atra")];
+    /// let prakriyas = v.derive_vakyas(&padas);
+    /// assert_eq!(prakriyas[0].vakya_text(), "rAmo 'tra");
+    /// ```
+    pub fn vakya_text(&self) -> String {
+        if self.stage != Stage::Vakya {
+            return self.text();
+        }
+
+        let mut ret = String::new();
+        let mut prev: Option<&Term> = None;
+        // Terms that aren't padas, such as agamas added during sandhi, belong to the next pada.
+        let mut pending = String::new();
+        for t in &self.terms {
+            if !t.is_pada() {
+                pending.push_str(&t.text);
+                continue;
+            }
+
+            pending.push_str(&t.text);
+            if let Some(prev) = prev {
+                ret.push_str(pada_separator(prev, t, &pending));
+            }
+            ret.push_str(&pending);
+            pending.clear();
+            prev = Some(t);
+        }
+        ret.push_str(&pending);
+        ret
+    }
+
+    /// Returns a broad IPA transcription of this derivation's text.
+    ///
+    /// This is a pure output transform on `vakya_text()`. For details, see `to_ipa`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let args = Tinanta::new(bhu, Prayoga::Kartari, Lakara::Lat, Purusha::Prathama, Vacana::Eka);
+    /// let prakriyas = v.derive_tinantas(&args);
+    /// assert_eq!(prakriyas[0].text_ipa(), "bʱɐʋɐt̪i");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn text_ipa(&self) -> String {
+        sounds::to_ipa(&self.vakya_text())
+    }
+
     /// Returns all of the optional rules that were encountered during the derivation and whether
     /// they were accepted or rejected.
+    ///
+    /// Choices are listed in the order the derivation encountered them. Every optional rule has
+    /// exactly two options, so each `RuleChoice` also describes the branch not taken (see
+    /// `Decision::opposite`). Together with `history()`, this is enough to reconstruct the tree
+    /// of derivations that `Vyakarana` explores.
     pub fn rule_choices(&self) -> &[RuleChoice] {
         &self.rule_choices
     }
@@ -245,11 +583,372 @@ impl Prakriya {
         &self.history
     }
 
+    /// Returns the steps of this derivation. This is the same as `history()`.
+    ///
+    /// Each step has a stable index: for a given derivation, `LogSteps::RulesOnly` and
+    /// `LogSteps::Full` record the same rules in the same order, so an index into one history
+    /// refers to the same rule in the other.
+    pub fn steps(&self) -> &[Step] {
+        &self.history
+    }
+
+    /// Returns the number of steps in this derivation.
+    pub fn step_count(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns the step at index `i`, or `None` if `i` is out of bounds.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let prakriyas = v.derive_krdantas(&Krdanta::new(bhu, BaseKrt::tavya));
+    ///
+    /// let p = &prakriyas[0];
+    /// let last = p.get_step(p.step_count() - 1).expect("has steps");
+    /// let text: String = last.result().iter().map(|t| t.text()).collect();
+    /// assert_eq!(text, p.text());
+    /// assert!(p.get_step(p.step_count()).is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_step(&self, i: usize) -> Option<&Step> {
+        self.history.get(i)
+    }
+
     /// (experimental) Returns the semantic condition (artha) under which this derivation was
     /// created.
     pub fn artha(&self) -> Option<Artha> {
         self.artha
     }
+
+    /// Returns the *saṃjñā*s that apply to the terms of this derivation.
+    ///
+    /// The result is the union of the *saṃjñā*s on each term, so a *tiṅanta* will contain both
+    /// `Samjna::Dhatu` and `Samjna::Tin`.
+    pub fn final_term_tags(&self) -> Vec<Samjna> {
+        let mut ret: EnumSet<Samjna> = EnumSet::new();
+        for t in &self.terms {
+            ret.extend(t.tags.iter().filter_map(Samjna::from_tag));
+        }
+        ret.iter().collect()
+    }
+
+    /// Returns whether the output of this derivation is an *avyaya*.
+    ///
+    /// Avyayas include *nipāta*s (1.1.37), krdantas like *kartum* and *kṛtvā* (1.1.39 - 1.1.40),
+    /// and *avyayībhāva samāsa*s (1.1.41). Programs can use this method to avoid declining these
+    /// words.
+    pub fn is_avyaya(&self) -> bool {
+        if self.has_tag(PT::Avyayibhava) {
+            return true;
+        }
+        self.terms
+            .iter()
+            .rev()
+            .find(|t| !t.is_sup())
+            .map_or(false, |t| t.is_avyaya())
+    }
+
+    /// Returns whether the output of this derivation is a *tiṅanta*.
+    pub fn is_tinanta(&self) -> bool {
+        self.terms.last().map_or(false, |t| t.is_tin())
+    }
+
+    /// Returns whether the output of this derivation is a *subanta*.
+    ///
+    /// *Avyaya*s are also *subanta*s, since their *sup-pratyaya* is removed by *luk* (2.4.82).
+    pub fn is_subanta(&self) -> bool {
+        self.terms.last().map_or(false, |t| t.is_sup())
+    }
+
+    /// Returns whether the nominal stem of this derivation ends in a *kṛt pratyaya*.
+    ///
+    /// Any *sup* or *strī pratyaya* after the stem is ignored, so both *kṛta* and *kṛtā* are
+    /// *kṛdanta*s.
+    pub fn is_krdanta(&self) -> bool {
+        self.last_stem_term().map_or(false, |t| t.is_krt())
+    }
+
+    /// Returns whether the nominal stem of this derivation ends in a *taddhita pratyaya*.
+    ///
+    /// As with `is_krdanta`, any *sup* or *strī pratyaya* after the stem is ignored.
+    pub fn is_taddhitanta(&self) -> bool {
+        self.last_stem_term().map_or(false, |t| t.is_taddhita())
+    }
+
+    /// Returns whether this derivation created a *samāsa*, including *upapada* compounds like
+    /// *kumbhakāra* (2.2.19).
+    pub fn is_samasa(&self) -> bool {
+        self.terms.iter().any(|t| t.is_samasa())
+    }
+
+    /// Returns the last term of the nominal stem, skipping any *sup* or *strī pratyaya*.
+    fn last_stem_term(&self) -> Option<&Term> {
+        self.terms
+            .iter()
+            .rev()
+            .find(|t| !t.is_sup() && !t.is_stri_pratyaya())
+    }
+
+    /// Returns the nominal stem that this derivation created if the derivation is a *kṛdanta* or
+    /// *taddhitānta* that has not yet received a *sup-pratyaya*.
+    ///
+    /// Dictionary generators can use this method to store stems as headwords without declining
+    /// them first.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let kr = Dhatu::mula(Slp1String::from("qukf\\Y")?, Gana::Tanadi);
+    /// let prakriyas = v.derive_krdantas(&Krdanta::new(kr, BaseKrt::kta));
+    ///
+    /// let entry = prakriyas[0].as_pratipadika_entry().expect("is a krdanta");
+    /// assert_eq!(entry.text(), "kfta");
+    /// assert_eq!(entry.antya_kind(), 'a');
+    /// assert_eq!(entry.likely_linga(), None);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn as_pratipadika_entry(&self) -> Option<PratipadikaEntry> {
+        let last = self.terms.last()?;
+        if self.failure.is_some() || !(last.is_krt() || last.is_taddhita()) {
+            return None;
+        }
+
+        let text: String = self.terms.iter().map(|t| t.text.as_str()).collect();
+        let antya_kind = text.chars().last()?;
+        let likely_linga = if self.has_tag(PT::Stri) {
+            Some(Linga::Stri)
+        } else if self.has_tag(PT::Pum) {
+            Some(Linga::Pum)
+        } else if self.has_tag(PT::Napumsaka) {
+            Some(Linga::Napumsaka)
+        } else {
+            None
+        };
+
+        Some(PratipadikaEntry {
+            text,
+            antya_kind,
+            likely_linga,
+        })
+    }
+
+    /// Returns a short summary of how this derivation's output was formed, e.g. `"kfta (kf + kta)"`.
+    ///
+    /// The summary lists the *upasarga*s, the *dhātu* or *prātipadika* that the derivation
+    /// started with, and each *sanādi*, *kṛt*, and *taddhita pratyaya* in the order they were
+    /// added. *Dhātu*s are shown without their *it* letters, and *pratyaya*s are shown in their
+    /// *aupadeśika* form. This method returns `None` if the derivation failed or did not add any
+    /// of these *pratyaya*s.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let kr = Dhatu::mula(Slp1String::from("qukf\\Y")?, Gana::Tanadi);
+    /// let prakriyas = v.derive_krdantas(&Krdanta::new(kr, BaseKrt::kta));
+    /// assert_eq!(prakriyas[0].formation_summary().unwrap(), "kfta (kf + kta)");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn formation_summary(&self) -> Option<String> {
+        if self.failure.is_some() {
+            return None;
+        }
+
+        let mut parts = Vec::new();
+        let mut has_affix = false;
+        for t in &self.terms {
+            if let Some(u) = t.morph_aupadeshika() {
+                parts.push(u.to_string());
+                has_affix |= !t.is_upasarga();
+            } else if let Some(u) = &t.u {
+                if t.is_dhatu() && !t.is_abhyasa() {
+                    parts.push(Self::dhatu_lemma(u));
+                } else if t.is_upasarga() || t.is_basic_pratipadika() {
+                    parts.push(u.to_string());
+                }
+            }
+        }
+
+        if has_affix {
+            Some(format!("{} ({})", self.text(), parts.join(" + ")))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `upadesha` without its *it* letters.
+    fn dhatu_lemma(upadesha: &str) -> String {
+        let mut p = Prakriya::new();
+        let mut dhatu = Term::make_upadesha(upadesha);
+        dhatu.add_tag(Tag::Dhatu);
+        p.push(dhatu);
+        match crate::it_samjna::run(&mut p, 0) {
+            Ok(()) => p.terms[0].text.to_string(),
+            Err(_) => upadesha.to_string(),
+        }
+    }
+
+    /// Returns why this derivation failed, or `None` if it succeeded.
+    ///
+    /// Failed derivations are returned only if `explain_failures` is enabled on `Vyakarana`. In
+    /// that case, `history()` shows the rules that applied before the derivation stopped.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    /// Compares the history of this derivation to the history of `other` and returns the steps
+    /// where they differ.
+    ///
+    /// The two histories are aligned step by step. The result starts at the first step where the
+    /// two histories diverge, either because a different rule applied or because the same rule
+    /// produced a different result, and continues until both histories end. If the histories are
+    /// identical, the result is empty.
+    ///
+    /// Both derivations must record their history (see `LogSteps`).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let tavya = v.derive_krdantas(&Krdanta::new(bhu.clone(), BaseKrt::tavya));
+    /// let tfc = v.derive_krdantas(&Krdanta::new(bhu, BaseKrt::tfc));
+    ///
+    /// let diff = tavya[0].diff(&tfc[0]);
+    /// let first = &diff[0];
+    /// assert_eq!(first.left().map(|s| s.rule()), Some(Rule::Ashtadhyayi("3.1.96")));
+    /// assert_eq!(first.right().map(|s| s.rule()), Some(Rule::Ashtadhyayi("3.1.133")));
+    /// assert!(tavya[0].diff(&tavya[0]).is_empty());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn diff(&self, other: &Prakriya) -> Vec<StepDiff> {
+        let left = self.history();
+        let right = other.history();
+        let n = std::cmp::max(left.len(), right.len());
+
+        let start = match (0..n).find(|&i| left.get(i) != right.get(i)) {
+            Some(i) => i,
+            None => return Vec::new(),
+        };
+        (start..n)
+            .map(|i| StepDiff {
+                index: i,
+                left: left.get(i).cloned(),
+                right: right.get(i).cloned(),
+            })
+            .collect()
+    }
+
+    /// Returns whether this derivation passes the basic checks in `malformations()`.
+    pub fn is_well_formed(&self) -> bool {
+        self.malformations().is_empty()
+    }
+
+    /// Checks this derivation for obvious malformations and returns a description of each one.
+    ///
+    /// We check that the derivation succeeded, that it produced some text, that each term
+    /// contains only SLP1 sounds, and that no term still contains an *it* sound from its
+    /// *upadeśa*. The SLP1 check catches markers like `~` after a consonant or the svara marks
+    /// `\` and `^`. For the *it* check, we compare the first and last consonants of each term's
+    /// *upadeśa* against its *it* tags.
+    ///
+    /// We don't check for empty terms: a term deleted by *lopa* is just an empty term, so we
+    /// can't tell it apart from a term that the engine emptied by mistake.
+    ///
+    /// These checks are cheap and meant for debugging. An empty result does not mean that the
+    /// derivation is correct.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// use vidyut_prakriya::args::*;
+    ///
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let args = Krdanta::new(bhu, BaseKrt::ktvA);
+    /// let prakriyas = v.derive_krdantas(&args);
+    /// assert!(prakriyas[0].is_well_formed());
+    /// assert!(prakriyas[0].malformations().is_empty());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn malformations(&self) -> Vec<String> {
+        // Includes the anusvara, visarga, jihvamuliya, and upadhmaniya.
+        const OTHER: Set = Set::from("MHZV");
+        const ANUNASIKA: Set = Set::from("aAiIuUfFxeEoOyvl");
+
+        let mut ret = Vec::new();
+        if let Some(failure) = &self.failure {
+            ret.push(format!("derivation failed: {failure}"));
+        }
+        if self.terms.iter().all(|t| t.text.is_empty()) {
+            ret.push("derivation has no text".to_string());
+        }
+
+        let mut prev = None;
+        for (i, t) in self.terms.iter().enumerate() {
+            for c in t.text.chars() {
+                let is_ok = if c == '~' {
+                    prev.map_or(false, |p| ANUNASIKA.contains(p))
+                } else {
+                    AL.contains(c) || OTHER.contains(c)
+                };
+                if !is_ok {
+                    ret.push(format!("term {i} (`{}`) has unexpected `{c}`", t.text));
+                }
+                prev = Some(c);
+            }
+
+            for c in leftover_its(t) {
+                ret.push(format!("term {i} (`{}`) still has it `{c}`", t.text));
+            }
+        }
+
+        ret
+    }
+}
+
+/// Returns the *it* sounds that `t` seems to have kept from its *upadeśa*.
+///
+/// A final consonant of an *upadeśa* (1.3.3) or an initial consonant of a *pratyaya* (1.3.6 -
+/// 1.3.8) that is marked as an *it* should have been deleted by 1.3.9. If the term still starts or
+/// ends with that consonant, we assume that it was never deleted.
+fn leftover_its(t: &Term) -> Vec<char> {
+    let mut ret = Vec::new();
+    let u = match &t.u {
+        Some(u) => u,
+        None => return ret,
+    };
+    let mut sounds = u.chars().filter(|c| !matches!(c, '\\' | '^' | '~'));
+    let first = sounds.next();
+    let last = sounds.next_back();
+
+    let is_it = |c: char| sounds::is_hal(c) && Tag::parse_it(c).map_or(false, |x| t.has_tag(x));
+    if let Some(c) = first {
+        if t.is_pratyaya() && is_it(c) && t.text.starts_with(c) {
+            ret.push(c);
+        }
+    }
+    if let Some(c) = last {
+        if is_it(c) && t.text.ends_with(c) {
+            ret.push(c);
+        }
+    }
+    ret
 }
 
 /// Private API
@@ -292,6 +991,7 @@ impl Prakriya {
             artha: None,
             config: Config::new(),
             rule_choices: Vec::new(),
+            failure: None,
         }
     }
 
@@ -302,6 +1002,20 @@ impl Prakriya {
         p
     }
 
+    /// Creates an empty prakriya with the given config options, reusing the buffers of `self`.
+    ///
+    /// Only buffers are reused. All other state is reset as in `with_config`.
+    pub(crate) fn recycle(mut self, config: Config) -> Self {
+        let mut p = Prakriya::with_config(config);
+        self.terms.clear();
+        self.history.clear();
+        self.rule_choices.clear();
+        p.terms = self.terms;
+        p.history = self.history;
+        p.rule_choices = self.rule_choices;
+        p
+    }
+
     // Accessors
     // ---------
 
@@ -595,6 +1309,20 @@ impl Prakriya {
     // Basic mutators
     // --------------
 
+    /// Records why the derivation cannot continue and returns an `Error::Abort` for the current
+    /// rule choices.
+    pub(crate) fn abort(&mut self, reason: &str) -> Error {
+        self.failure = Some(reason.to_string());
+        Error::Abort(self.rule_choices.clone())
+    }
+
+    /// Records `reason` as the failure reason if no other reason has been set.
+    pub(crate) fn set_failure_if_empty(&mut self, reason: String) {
+        if self.failure.is_none() {
+            self.failure = Some(reason);
+        }
+    }
+
     /// Adds a tag to the prakriya.
     pub(crate) fn add_tag(&mut self, tag: PrakriyaTag) {
         self.tags.insert(tag.into());
@@ -610,6 +1338,11 @@ impl Prakriya {
         self.artha = Some(artha);
     }
 
+    /// Clears the artha corresponding to this prakriya.
+    pub(crate) fn clear_artha(&mut self) {
+        self.artha = None;
+    }
+
     pub(crate) fn remove_tag(&mut self, tag: PrakriyaTag) {
         self.tags.remove(tag);
     }
@@ -662,9 +1395,13 @@ impl Prakriya {
     ///
     /// `rule` will be recorded regardless of whether or not `operator` caused any changes.
     ///
-    /// Returns: `true`. We return a boolean value for consistency with functions like
-    /// `run_optional`.
+    /// Returns: whether `func` was applied, which is `true` unless `rule` has been disabled. We
+    /// return a boolean value for consistency with functions like `run_optional`.
     pub(crate) fn run(&mut self, rule: impl Into<Rule>, func: impl Fn(&mut Prakriya)) -> bool {
+        let rule = rule.into();
+        if self.fail_if_disabled(rule) {
+            return false;
+        }
         func(self);
         self.step(rule);
         true
@@ -677,9 +1414,13 @@ impl Prakriya {
         index: usize,
         func: impl Fn(&mut Term),
     ) -> bool {
+        let rule = rule.into();
+        if self.fail_if_disabled(rule) {
+            return false;
+        }
         if let Some(term) = self.get_mut(index) {
             func(term);
-            self.step(rule.into());
+            self.step(rule);
             true
         } else {
             false
@@ -702,6 +1443,9 @@ impl Prakriya {
         func: impl FnOnce(Rule, &mut Prakriya),
     ) -> bool {
         let rule = rule.into();
+        if self.is_disabled(rule) {
+            return false;
+        }
         let decision = self.decide(rule);
         match decision {
             Some(Decision::Accept) | None => {
@@ -766,10 +1510,24 @@ impl Prakriya {
     }
 
     /// Adds `rule` and the current derivation state to the derivation history.
+    ///
+    /// If `rule` has been disabled, marks this derivation as failed instead.
     pub(crate) fn step(&mut self, rule: impl Into<Rule>) {
-        if !self.config.log_steps {
+        let rule = rule.into();
+        if self.fail_if_disabled(rule) {
             return;
         }
+        match self.config.log_steps {
+            LogSteps::None => return,
+            LogSteps::RulesOnly => {
+                self.history.push(Step {
+                    rule,
+                    result: Vec::new(),
+                });
+                return;
+            }
+            LogSteps::Full => (),
+        }
 
         let mut result: Vec<StepTerm> = self
             .terms
@@ -807,10 +1565,7 @@ impl Prakriya {
             result.iter_mut().for_each(|x| x.was_changed = true);
         }
 
-        self.history.push(Step {
-            rule: rule.into(),
-            result,
-        })
+        self.history.push(Step { rule, result })
     }
 
     // Optional rules
@@ -831,6 +1586,38 @@ impl Prakriya {
         self.config.nlp_mode
     }
 
+    /// Returns whether the user has disabled any rules.
+    pub(crate) fn has_disabled_rules(&self) -> bool {
+        !self.config.disabled_rules.is_empty()
+    }
+
+    /// Returns whether the user has disabled `rule`.
+    pub(crate) fn is_disabled(&self, rule: Rule) -> bool {
+        self.config.disabled_rules.contains(&rule)
+    }
+
+    /// Returns whether the user has disabled `rule`. If so, also marks this derivation as failed.
+    ///
+    /// Later rules assume that a required rule has applied, so we can't safely continue a
+    /// derivation that skips one.
+    pub(crate) fn fail_if_disabled(&mut self, rule: Rule) -> bool {
+        if self.is_disabled(rule) {
+            self.set_failure_if_empty(format!("rule {} is disabled", rule.code()));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an `Error::Abort` if this derivation has failed, e.g. because it needed a rule
+    /// that the user disabled.
+    pub(crate) fn check_failure(&self) -> Result<()> {
+        match self.failure {
+            Some(_) => Err(Error::Abort(self.rule_choices.clone())),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn decide(&self, r: impl Into<Rule>) -> Option<Decision> {
         let r = r.into();
         for choice in &self.config.rule_choices {
@@ -869,13 +1656,19 @@ impl Prakriya {
     #[allow(unused)]
     #[cfg(debug_assertions)]
     pub(crate) fn debug(&mut self, text: impl AsRef<str>) {
-        self.history.push(Step {
-            rule: Rule::Ashtadhyayi("    "),
-            result: vec![StepTerm {
+        let result = match self.config.log_steps {
+            LogSteps::None => return,
+            // Still push a step so that step indices match those for `LogSteps::Full`.
+            LogSteps::RulesOnly => Vec::new(),
+            LogSteps::Full => vec![StepTerm {
                 text: text.as_ref().to_string(),
                 tags: EnumSet::new(),
                 was_changed: false,
             }],
+        };
+        self.history.push(Step {
+            rule: Rule::Ashtadhyayi("    "),
+            result,
         });
     }
 
@@ -915,3 +1708,57 @@ impl Prakriya {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformations() {
+        let mut p = Prakriya::new();
+        assert_eq!(p.malformations(), vec!["derivation has no text"]);
+
+        p.push(Term::make_text("Bava"));
+        p.push(Term::make_text("ti"));
+        assert!(p.is_well_formed());
+
+        // nasal vowels are fine.
+        p.push(Term::make_text("a~"));
+        assert!(p.is_well_formed());
+
+        // leftover markers from an upadesha are not.
+        p.push(Term::make_text("ga\\mx~"));
+        p.push(Term::make_text("tip~"));
+        assert_eq!(
+            p.malformations(),
+            vec![
+                "term 3 (`ga\\mx~`) has unexpected `\\`",
+                "term 4 (`tip~`) has unexpected `~`",
+            ]
+        );
+        assert!(!p.is_well_formed());
+    }
+
+    #[test]
+    fn malformations_with_leftover_its() {
+        let mut tip = Term::make_upadesha("tip");
+        tip.add_tag(Tag::pit);
+        let mut p = Prakriya::new();
+        p.push(Term::make_text("Bava"));
+        p.push(tip);
+        assert_eq!(p.malformations(), vec!["term 1 (`tip`) still has it `p`"]);
+
+        // Once 1.3.9 applies, the term is fine.
+        p.set(1, |t| t.set_text("ti"));
+        assert!(p.is_well_formed());
+
+        // Initial its are checked too.
+        let mut sap = Term::make_upadesha("Sap");
+        sap.add_tags(&[Tag::Pratyaya, Tag::Sit, Tag::pit]);
+        sap.set_text("Sa");
+        let mut p = Prakriya::new();
+        p.push(Term::make_text("Bav"));
+        p.push(sap);
+        assert_eq!(p.malformations(), vec!["term 1 (`Sa`) still has it `S`"]);
+    }
+}
@@ -1,5 +1,18 @@
 use crate::core::errors::*;
-use crate::core::{Config, Decision, Prakriya, RuleChoice};
+use crate::core::{Config, LogSteps, Prakriya, Rule, RuleChoice};
+
+/// Controls which optional derivations a `PrakriyaStack` returns.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OptionalMode {
+    /// Return the results of every combination of optional rules.
+    #[default]
+    All,
+    /// Return only the first result, which accepts every optional rule it can.
+    ///
+    /// If accepting an optional rule causes the derivation to fail, we continue to search until
+    /// we find a derivation that succeeds.
+    PreferredOnly,
+}
 
 /// Explores all optional derivations for some input.
 ///
@@ -8,61 +21,128 @@ use crate::core::{Config, Decision, Prakriya, RuleChoice};
 /// and exploring the various combinations of optional rules.
 #[derive(Default)]
 pub(crate) struct PrakriyaStack {
-    /// Whether a prakriya should log its steps.
-    log_steps: bool,
+    /// How much of its history a prakriya should log.
+    log_steps: LogSteps,
     /// Whether a prakriya should use chAndasa rules.
     is_chandasi: bool,
     /// Whether svara rules are enabled.
     use_svaras: bool,
     /// Whether NLP mode is enabled.
     nlp_mode: bool,
+    /// Which optional derivations to return.
+    optional_mode: OptionalMode,
+    /// Whether to keep failed prakriyas so that we can explain them.
+    explain_failures: bool,
+    /// Rules that a prakriya should never apply.
+    disabled_rules: Vec<Rule>,
+    /// Decisions that every prakriya must make for the given optional rules.
+    fixed_choices: Vec<RuleChoice>,
 
     /// Completed prakriyas.
     prakriyas: Vec<Prakriya>,
+    /// Failed prakriyas, if `explain_failures` is set.
+    failures: Vec<Prakriya>,
     /// Combinations of optional rules that we have yet to try.
     paths: Vec<Vec<RuleChoice>>,
 }
 
 impl PrakriyaStack {
     /// Creates an empty `PrakriyaStack`.
-    pub fn new(log_steps: bool, is_chandasi: bool, use_svaras: bool, nlp_mode: bool) -> Self {
+    pub fn new(log_steps: LogSteps, is_chandasi: bool, use_svaras: bool, nlp_mode: bool) -> Self {
         Self {
             prakriyas: Vec::new(),
+            failures: Vec::new(),
             paths: Vec::new(),
             log_steps,
             is_chandasi,
             use_svaras,
             nlp_mode,
+            optional_mode: OptionalMode::All,
+            explain_failures: false,
+            disabled_rules: Vec::new(),
+            fixed_choices: Vec::new(),
         }
     }
 
+    /// Sets which optional derivations to return.
+    pub fn with_optional_mode(mut self, optional_mode: OptionalMode) -> Self {
+        self.optional_mode = optional_mode;
+        self
+    }
+
+    /// Sets which rules a prakriya should never apply.
+    pub fn with_disabled_rules(mut self, disabled_rules: &[Rule]) -> Self {
+        self.disabled_rules = disabled_rules.to_vec();
+        self
+    }
+
+    /// Sets which decisions a prakriya must make for the given optional rules.
+    pub fn with_fixed_choices(mut self, fixed_choices: &[RuleChoice]) -> Self {
+        self.fixed_choices = fixed_choices.to_vec();
+        self
+    }
+
+    /// Sets whether to keep failed prakriyas if no derivation succeeds.
+    pub fn with_explain_failures(mut self, explain_failures: bool) -> Self {
+        self.explain_failures = explain_failures;
+        self
+    }
+
     /// Creates a new `Prakriya` according to upstream options.
-    fn new_prakriya(&self, rule_choices: Vec<RuleChoice>) -> Prakriya {
-        Prakriya::with_config(Config {
+    ///
+    /// If `spare` is set, reuse its buffers to reduce allocations.
+    fn new_prakriya(&self, mut rule_choices: Vec<RuleChoice>, spare: Option<Prakriya>) -> Prakriya {
+        // `Prakriya::decide` uses the first match, so fixed choices take priority.
+        if !self.fixed_choices.is_empty() {
+            rule_choices.splice(0..0, self.fixed_choices.iter().copied());
+        }
+        let config = Config {
             rule_choices,
+            disabled_rules: self.disabled_rules.clone(),
             log_steps: self.log_steps,
             is_chandasi: self.is_chandasi,
             use_svaras: self.use_svaras,
             nlp_mode: self.nlp_mode,
-        })
+        };
+        match spare {
+            Some(p) => p.recycle(config),
+            None => Prakriya::with_config(config),
+        }
     }
 
     /// Finds all variants of the given derivation function.
     ///
     /// `derive` should accept an empty `Prakriya` and mutate it in-place.
-    pub fn find_all(&mut self, derive: impl Fn(Prakriya) -> Result<Prakriya>) {
+    ///
+    /// We explore paths depth-first, so the order of our results is deterministic: the first
+    /// result accepts every optional rule, and later results decline the most recent choices
+    /// first. Callers (and the public API) rely on this ordering, so don't change it lightly.
+    pub fn find_all(&mut self, derive: impl Fn(&mut Prakriya) -> Result<()>) {
         self.paths.push(vec![]);
 
+        // A failed prakriya whose buffers we can reuse for the next path.
+        let mut spare = None;
         while let Some(path) = self.pop_path() {
-            let p_init = self.new_prakriya(path.clone());
-            match derive(p_init) {
-                Ok(p) => {
-                    self.add_new_paths(p.rule_choices(), &path);
+            let offset = path.len();
+            let mut p = self.new_prakriya(path, spare.take());
+            match derive(&mut p).and_then(|()| p.check_failure()) {
+                Ok(()) => {
+                    if self.optional_mode == OptionalMode::PreferredOnly {
+                        self.prakriyas.push(p);
+                        return;
+                    }
+                    self.add_new_paths(p.rule_choices(), offset);
                     self.prakriyas.push(p);
                 }
                 Err(e) => {
-                    if let Error::Abort(choices) = e {
-                        self.add_new_paths(&choices, &path);
+                    if let Error::Abort(choices) = &e {
+                        self.add_new_paths(choices, offset);
+                    }
+                    if self.explain_failures {
+                        p.set_failure_if_empty(e.to_string());
+                        self.failures.push(p);
+                    } else {
+                        spare = Some(p);
                     }
                     // TODO: handle other errors better.
                 }
@@ -92,17 +172,32 @@ impl PrakriyaStack {
     ///
     /// > Decline(A), Decline(B)
     /// > Decline(A), Accept(B), Decline(D)
-    fn add_new_paths(&mut self, choices: &[RuleChoice], initial_choices: &[RuleChoice]) {
-        let offset = initial_choices.len();
+    ///
+    /// `offset` is the length of the path that produced `choices`.
+    ///
+    /// Fixed choices are never swapped, so we skip them here. (They are still added to each
+    /// prakriya's config by `new_prakriya`.)
+    fn add_new_paths(&mut self, choices: &[RuleChoice], offset: usize) {
+        if !self.fixed_choices.is_empty() {
+            let choices: Vec<_> = choices
+                .iter()
+                .filter(|c| !self.fixed_choices.iter().any(|f| f.rule == c.rule))
+                .copied()
+                .collect();
+            self.push_swapped_paths(&choices, offset);
+        } else {
+            self.push_swapped_paths(choices, offset);
+        }
+    }
+
+    /// Pushes one path for each choice in `choices[offset..]` with that choice swapped.
+    fn push_swapped_paths(&mut self, choices: &[RuleChoice], offset: usize) {
         for i in offset..choices.len() {
             let mut path = choices[..=i].to_vec();
 
             // Swap the last choice.
             let i = path.len() - 1;
-            path[i].decision = match path[i].decision {
-                Decision::Accept => Decision::Decline,
-                Decision::Decline => Decision::Accept,
-            };
+            path[i].decision = path[i].decision.opposite();
 
             self.paths.push(path);
         }
@@ -114,7 +209,14 @@ impl PrakriyaStack {
     }
 
     /// Returns all of the prakriyas this stack has found. This consumes the stack.
+    ///
+    /// If `explain_failures` is set and no derivation succeeded, returns the failed prakriyas
+    /// instead.
     pub fn prakriyas(self) -> Vec<Prakriya> {
-        self.prakriyas
+        if self.explain_failures && self.prakriyas.is_empty() {
+            self.failures
+        } else {
+            self.prakriyas
+        }
     }
 }
@@ -355,6 +355,8 @@ pub enum PrakriyaTag {
     FlagHetuBhaya,
     FlagHasAnitKsa,
     FlagHasSetSic,
+    /// Indicates that the caller chose the linga, so the linganushasana doesn't apply.
+    FlagHasLinga,
 
     // Placeholder for From<Tag> default case.
     Nothing,
@@ -385,3 +387,81 @@ impl From<Tag> for PrakriyaTag {
         }
     }
 }
+
+/// A *saṃjñā* that applies to some part of a completed derivation.
+///
+/// `Samjna` is a small and stable subset of the tags that we track internally. It is meant for
+/// programs that want to classify a derived word without re-parsing its text, e.g. to tell a
+/// *kṛdanta* from a *tiṅanta*.
+#[derive(Debug, EnumSetType)]
+pub enum Samjna {
+    /// A verb root.
+    Dhatu,
+    /// A prefix like *pra*, *upa*, etc.
+    Upasarga,
+    /// A nominal stem.
+    Pratipadika,
+    /// A suffix of any kind.
+    Pratyaya,
+    /// An indeclinable.
+    Avyaya,
+    /// A particle, which is also an *avyaya*.
+    Nipata,
+    /// A pronoun.
+    Sarvanama,
+    /// A compound.
+    Samasa,
+    /// A verb ending.
+    Tin,
+    /// A nominal ending.
+    Sup,
+    /// A suffix that creates nominal stems from verb roots.
+    Krt,
+    /// A *kṛt pratyaya* in the *kṛtya* section (3.1.95).
+    Krtya,
+    /// The *kṛt pratyaya*s *kta* and *ktavatu* (1.1.26).
+    Nistha,
+    /// A suffix that creates nominal stems from other nominal stems.
+    Taddhita,
+    /// The *parasmaipada* verb endings.
+    Parasmaipada,
+    /// The *ātmanepada* verb endings.
+    Atmanepada,
+    /// The masculine gender.
+    Pum,
+    /// The feminine gender.
+    Stri,
+    /// The neuter gender.
+    Napumsaka,
+}
+
+impl Samjna {
+    /// Returns the public `Samjna` that corresponds to the given `tag`, if one exists.
+    pub(crate) fn from_tag(tag: Tag) -> Option<Self> {
+        use Samjna as S;
+        use Tag as T;
+        let ret = match tag {
+            T::Dhatu => S::Dhatu,
+            T::Upasarga => S::Upasarga,
+            T::Pratipadika => S::Pratipadika,
+            T::Pratyaya => S::Pratyaya,
+            T::Avyaya => S::Avyaya,
+            T::Nipata => S::Nipata,
+            T::Sarvanama => S::Sarvanama,
+            T::Samasa => S::Samasa,
+            T::Tin => S::Tin,
+            T::Sup => S::Sup,
+            T::Krt => S::Krt,
+            T::Krtya => S::Krtya,
+            T::Nistha => S::Nistha,
+            T::Taddhita => S::Taddhita,
+            T::Parasmaipada => S::Parasmaipada,
+            T::Atmanepada => S::Atmanepada,
+            T::Pum => S::Pum,
+            T::Stri => S::Stri,
+            T::Napumsaka => S::Napumsaka,
+            _ => return None,
+        };
+        Some(ret)
+    }
+}
@@ -453,6 +453,19 @@ impl Term {
         }
     }
 
+    /// Returns the *aupadeśika* form of this term if it is an *upasarga* or a *sanādi*, *kṛt*,
+    /// or *taddhita pratyaya*.
+    pub(crate) fn morph_aupadeshika(&self) -> Option<&'static str> {
+        match self.morph {
+            Morph::Upasarga(x) => Some(x.aupadeshika()),
+            Morph::Sanadi(x) => Some(x.as_str()),
+            Morph::Krt(x) => Some(x.as_str()),
+            Morph::Unadi(x) => Some(x.as_str()),
+            Morph::Taddhita(x) => Some(x.as_str()),
+            _ => None,
+        }
+    }
+
     pub(crate) fn is_any_sup(&self, vals: &[Sup]) -> bool {
         if let Morph::Sup(t) = self.morph {
             vals.contains(&t)
@@ -598,6 +611,18 @@ impl Term {
         matches!(self.morph, Morph::BasicPratipadika)
     }
 
+    /// Returns whether the term is a monosyllabic basic pratipadika that ends in `I` or `U`, such
+    /// as *śrī*, *dhī*, or *bhū*.
+    ///
+    /// These pratipadikas are *kvip-anta*s (e.g. *śrī* from *śriñ*), so rules that apply to a
+    /// *dhātu* such as 6.4.77 also apply to them. *strī* is the one exception.
+    pub fn is_ekac_dhatu_pratipadika(&self) -> bool {
+        self.is_basic_pratipadika()
+            && self.num_vowels() == 1
+            && (self.has_antya('I') || self.has_antya('U'))
+            && !self.has_text("strI")
+    }
+
     /// Returns whether the term has the `Dhatu` samjna.
     pub fn is_dhatu(&self) -> bool {
         self.has_tag(Tag::Dhatu)
@@ -289,6 +289,7 @@ pub fn try_add_prefixes(p: &mut Prakriya, prefixes: &[String]) -> Option<()> {
 /// Adds a dhatu to the prakriya and runs various follow-up rules on it.
 pub fn run(p: &mut Prakriya, dhatu: &Muladhatu) -> Result<()> {
     add_mula_dhatu(p, dhatu);
+    p.check_failure()?;
     let i_dhatu = p.terms().len() - 1;
 
     if p.has(i_dhatu, |t| t.has_u("CadiH")) {
@@ -3,7 +3,7 @@ Utility functions for working with the Dhatupatha file included in this crate. F
 comments on the `Dhatupatha` struct.
 */
 
-use crate::args::{Antargana, Dhatu, Gana};
+use crate::args::{Antargana, Dhatu, Gana, Sanadi};
 use crate::core::errors::*;
 use std::path::Path;
 
@@ -16,13 +16,7 @@ pub struct Entry {
 
 impl Entry {
     fn parse(code: &str, upadesha: &str, artha: &str) -> Result<Self> {
-        let (gana, number) = code.split_once('.').ok_or(Error::InvalidFile)?;
-        let gana = if let Some(stripped) = gana.strip_prefix('0') {
-            stripped.parse()?
-        } else {
-            gana.parse()?
-        };
-        let number = number.parse()?;
+        let (gana, number) = parse_code(code)?;
         let dhatu = create_dhatu(upadesha, gana, number)?;
 
         Ok(Self {
@@ -96,6 +90,31 @@ pub fn create_dhatu(upadesha: impl AsRef<str>, gana: Gana, number: u16) -> Resul
     builder.build()
 }
 
+/// Parses a Dhatupatha code (e.g. `"01.0001"`) into its gana and its position within that gana.
+fn parse_code(code: &str) -> Result<(Gana, u16)> {
+    let (gana, number) = code.split_once('.').ok_or(Error::InvalidFile)?;
+    let gana = if let Some(stripped) = gana.strip_prefix('0') {
+        stripped.parse()?
+    } else {
+        gana.parse()?
+    };
+    let number = number.parse()?;
+    Ok((gana, number))
+}
+
+/// Creates a dhatu from the first three fields of a spec string (`upadesha|code|sanadi`).
+///
+/// `sanadi` is a `+`-separated list of sanAdi-pratyayas and may be empty.
+pub(crate) fn create_dhatu_from_spec(upadesha: &str, code: &str, sanadi: &str) -> Result<Dhatu> {
+    let (gana, number) = parse_code(code)?;
+    let sanadi = sanadi
+        .split('+')
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse())
+        .collect::<Result<Vec<Sanadi>>>()?;
+    Ok(create_dhatu(upadesha, gana, number)?.with_sanadi(&sanadi))
+}
+
 impl Dhatupatha {
     /// Loads a dhatupatha from the provided TSV.
     ///
@@ -132,6 +151,41 @@ impl Dhatupatha {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn from_text(csv: &str) -> Result<Self> {
+        let mut dhatus = Self::parse_entries(csv)?;
+        dhatus.sort_by(|x, y| x.code.cmp(&y.code));
+        Ok(Self(dhatus))
+    }
+
+    /// Applies the readings of another recension on top of this dhatupatha.
+    ///
+    /// `csv` has the same format as the input to [`Dhatupatha::from_text`]. Each of its entries
+    /// replaces the entry with the same code, if one exists, and is otherwise added as a new
+    /// entry. Use this method to compare derivations across variant readings of the Dhatupatha
+    /// (e.g. those of the *Mādhavīyadhātuvṛttiḥ* and the *Kṣīrataraṅgiṇī*).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Error;
+    /// # use vidyut_prakriya::dhatupatha::Dhatupatha;
+    /// let base = "code\tdhatu\tartha\n01.0001\tBU\tsattAyAm\n01.0002\tzPA\tvfdDO";
+    /// let variant = "code\tdhatu\tartha\n01.0002\tsPAyI~\\\tvfdDO";
+    /// let d = Dhatupatha::from_text(base)?.with_recension(variant)?;
+    /// assert_eq!(d.get("01.0002").unwrap().aupadeshika(), Some("sPAyI~\\"));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn with_recension(mut self, csv: &str) -> Result<Self> {
+        for entry in Self::parse_entries(csv)? {
+            match self.0.binary_search_by(|x| x.code.cmp(&entry.code)) {
+                Ok(i) => self.0[i] = entry,
+                Err(i) => self.0.insert(i, entry),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Parses the entries in `csv` in their file order.
+    fn parse_entries(csv: &str) -> Result<Vec<Entry>> {
         let mut dhatus = Vec::new();
         for (i, line) in csv.split('\n').enumerate() {
             // Skip header.
@@ -161,15 +215,18 @@ impl Dhatupatha {
             let entry = Entry::parse(code, upadesha, artha)?;
             dhatus.push(entry);
         }
-
-        dhatus.sort_by(|x, y| x.code.cmp(&y.code));
-        Ok(Self(dhatus))
+        Ok(dhatus)
     }
 
     /// Gets the dhatu with the given code.
     pub fn get(&self, code: &str) -> Option<&Dhatu> {
+        self.get_entry(code).map(|e| &e.dhatu)
+    }
+
+    /// Gets the full entry (dhatu, code, and artha) with the given code.
+    pub fn get_entry(&self, code: &str) -> Option<&Entry> {
         match self.0.binary_search_by_key(&code, |d| &d.code) {
-            Ok(i) => Some(&self.0.get(i)?.dhatu),
+            Ok(i) => self.0.get(i),
             Err(_) => None,
         }
     }
@@ -246,6 +303,34 @@ mod tests {
         assert!(dhatu.sanadi().is_empty());
     }
 
+    #[test]
+    fn with_recension() {
+        let text = "code\tdhatu\tartha\n01.0001\tBU\tsattAyAm\n01.0003\tGawa~\\\tcezwAyAm";
+        let variant = "code\tdhatu\tartha\n01.0003\tGawa~\tceze\n01.0002\tEDa~\\\tvfdDO";
+        let base = Dhatupatha::from_text(text).unwrap();
+        let other = Dhatupatha::from_text(text)
+            .unwrap()
+            .with_recension(variant)
+            .unwrap();
+
+        // Unchanged entry.
+        assert_eq!(base.get("01.0001"), other.get("01.0001"));
+
+        // Replaced entry: different upadesha and artha.
+        let x = base.get_entry("01.0003").unwrap();
+        let y = other.get_entry("01.0003").unwrap();
+        assert_eq!(x.dhatu().aupadeshika(), Some("Gawa~\\"));
+        assert_eq!(y.dhatu().aupadeshika(), Some("Gawa~"));
+        assert_eq!(x.artha(), "cezwAyAm");
+        assert_eq!(y.artha(), "ceze");
+        assert_ne!(x.dhatu(), y.dhatu());
+
+        // New entry, kept in code order.
+        assert!(base.get("01.0002").is_none());
+        let codes: Vec<_> = other.iter().map(|e| e.code()).collect();
+        assert_eq!(codes, vec!["01.0001", "01.0002", "01.0003"]);
+    }
+
     #[test]
     fn create_dhatu_with_adhii() {
         let i_n = create_dhatu("i\\N", Gana::Adadi, 41).unwrap();
@@ -357,14 +357,16 @@ pub const SUKHA_ADI: &[&str] = &[
 ];
 
 /// 3.1.27 kaRqvAdiByo yak (35)
-#[allow(unused)]
+///
+/// We also include "mfga" so that we can derive `mfgyati` (SK 2573), which the Kashika's list
+/// doesn't cover.
 pub const KANDU_ADI: &[&str] = &[
     "kaRqUY", "mantu", "mantuY", "valgu", "asu~", "asU", "asUY", "lew", "low", "lelA", "iras",
     "iraj", "iraY", "uzas", "veda", "meDA", "kuzuBa", "magaDa", "tantas", "pampas", "suKa",
     "duHKa", "sapara", "arara", "BizajU", "BizRaj", "izuDa", "caraRa", "varaRa", "curaRa",
     "turaRa", "BuraRa", "gadgada", "elA", "kelA", "KelA", "ilA", "leKA", "leKa", "liwa", "lAwa",
     "hfRIN", "mahIN", "reKA", "dravas", "tiras", "agada", "uras", "taraRa", "payas", "samBUyas",
-    "ambara", "saMvara",
+    "ambara", "saMvara", "mfga",
 ];
 
 /// 4.1.4 ajAdyataz wAp (54)
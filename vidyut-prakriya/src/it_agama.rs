@@ -12,6 +12,10 @@ called *iṭ*. Dhatus use iṭ in one of three patterns:
 - Roots that generally avoid iṭ are called *aniṭ* (an-iṭ).
 - Roots that optionally use iṭ are called *veṭ* (vā-iṭ).
 
+For *veṭ* roots, we model the option as an optional rule that either adds or blocks iṭ (e.g.
+7.2.44). The decision is recorded in `Prakriya::rule_choices`, and callers can force either branch
+with `VyakaranaBuilder::rule_choices`.
+
 The main rules here are:
 
 - 7.2.35, which adds *iṭ* before ardhadhatuka suffixes that start with a consonant other than *y*.
@@ -216,6 +220,33 @@ fn try_dirgha_for_it_agama(p: &mut Prakriya, i_it: usize) -> Option<()> {
     Some(())
 }
 
+/// Returns whether `anga` will replace its vowel with `e` and lose its abhyasa in liw (6.4.120
+/// and 6.4.122).
+///
+/// iw-Agama runs before dvitva, so we "look ahead" to see whether the dhatu will have one vowel
+/// after dvitva.
+fn will_have_et_abhyasa_lopa(anga: &Term) -> bool {
+    // Sounds that change in the abhyasa by 7.4.62 and 8.4.54.
+    const KUH: Set = s(&["ku~", "h"]);
+    const MAHAPRANA: Set = Set::from("KGCJWQTDPB");
+
+    if anga.has_text_in(&["Pal", "Baj", "trap"]) {
+        // PelivAn, BejivAn, trepivAn
+        true
+    } else if anga.has_text_in(&["Sas", "dad"]) || anga.has_adi('v') {
+        // Blocked by 6.4.126.
+        false
+    } else {
+        // pecivAn, SekivAn, sedivAn, ...
+        anga.len() == 3
+            && anga.has_adi(HAL)
+            && anga.has_upadha('a')
+            && anga.has_antya(HAL)
+            && !anga.has_adi(KUH)
+            && !anga.has_adi(MAHAPRANA)
+    }
+}
+
 fn run_valadau_ardhadhatuke_before_attva_for_term(ip: &mut ItPrakriya) -> Option<()> {
     let anga = ip.anga();
     let n = ip.next();
@@ -258,7 +289,8 @@ fn run_valadau_ardhadhatuke_before_attva_for_term(ip: &mut ItPrakriya) -> Option
             "vada~",
         ]);
         // Dhatus that undergo ettva-abhyAsalopa (pecivas, Sekivas, ...)
-        let will_be_eka_ac = is_ac_adi || will_be_ac_adi;
+        let will_have_et = will_have_et_abhyasa_lopa(anga);
+        let will_be_eka_ac = is_ac_adi || will_be_ac_adi || will_have_et;
 
         let code = "7.2.67";
         if will_be_eka_ac || anga.has_antya('A') || anga.has_text("Gas") {
@@ -917,11 +917,25 @@ fn try_add_upapada_krt(kp: &mut KrtPrakriya) -> Option<bool> {
     }
 
     if kp.has_krt && krt == kvip {
+        // kvib-vaci-pracCy-Ayatastu-kawapru-ju-SrIRAM dIrGo 'saMprasAraRaM ca
         let dhatu = kp.dhatu_start();
-        if dhatu.has_text("Sri") {
+        let upapada = kp.p.get_if(0, |t| t.has_tag(T::Pratipadika));
+        let has_upapada = |text| upapada.map_or(false, |t| t.has_text(text));
+        if dhatu.has_u_in(&["va\\ca~", "pra\\Ca~"]) {
+            // vAc, prAS
+            kp.p.run_at(Varttika("3.2.178.1"), i_dhatu, |t| {
+                t.set_upadha("A");
+                t.add_tag(T::FlagSamprasarana);
+            });
+        } else if dhatu.has_text("Sri") {
             // SrI
-            // TODO: others
             kp.p.run_at(Varttika("3.2.178.1"), i_dhatu, |t| t.set_antya("I"));
+        } else if dhatu.has_text("ju")
+            || (has_upapada("Ayata") && dhatu.has_text("stu"))
+            || (has_upapada("kawa") && dhatu.has_text("pru"))
+        {
+            // jU, AyatastU, kawaprU
+            kp.p.run_at(Varttika("3.2.178.1"), i_dhatu, |t| t.set_antya("U"));
         }
     }
 
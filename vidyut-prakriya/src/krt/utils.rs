@@ -202,6 +202,7 @@ impl<'a> KrtPrakriya<'a> {
                 t.remove_tag(T::fdit);
             });
             op::adesha(rule, self.p, i_lakara, krt.as_str());
+            self.p.set(i_lakara, |t| t.morph = Morph::Krt(krt));
             self.has_krt = true;
             true
         } else {
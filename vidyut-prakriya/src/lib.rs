@@ -2,9 +2,13 @@
 #![deny(missing_docs)]
 #![deny(clippy::unwrap_used)]
 
-pub use crate::core::{Decision, Error, Prakriya, Rule, RuleChoice, Step};
+pub use crate::core::{
+    Decision, Error, LogSteps, OptionalMode, Prakriya, PratipadikaEntry, Rule, RuleChoice, Samjna,
+    Step, StepDiff,
+};
 pub use crate::dhatupatha::Dhatupatha;
-pub use crate::vyakarana::{Vyakarana, VyakaranaBuilder};
+pub use crate::sounds::{to_guna, to_ipa, to_vrddhi};
+pub use crate::vyakarana::{CacheStats, TinantaTable, Vyakarana, VyakaranaBuilder};
 
 // Public modules.
 // - `args` defines the API contract.
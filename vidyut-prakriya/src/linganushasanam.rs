@@ -4,6 +4,7 @@ Implements rules from the pāṇiṇīyaliṅgānuśāśanam, which assigns ling
 
 use crate::args::BaseKrt as K;
 use crate::args::Taddhita as D;
+use crate::args::Unadi;
 use crate::core::{Prakriya, Rule};
 use crate::core::{PrakriyaTag as PT, Tag as T};
 
@@ -117,7 +118,7 @@ impl<'a> LingaPrakriya<'a> {
 pub fn run(p: &mut Prakriya) -> Option<()> {
     use Rule::Linganushasana as L;
 
-    if p.has_tag(PT::Stri) {
+    if p.has_tag(PT::Stri) || p.has_tag(PT::FlagHasLinga) {
         return None;
     }
 
@@ -191,6 +192,12 @@ pub fn run(p: &mut Prakriya) -> Option<()> {
             }
         } else if dhatu.has_tag(T::Ghu) && last.has_u("ki") {
             lp.mark_pum(L("41"));
+        } else if last.is(K::zwran) || last.is(Unadi::zwran) {
+            // netra, Sastra, vastra, pAtra, ...
+            //
+            // dvArAdi (90) lists many zwran-antas, but we check it before guna, so we can't match
+            // stems like `netra` by their text.
+            lp.mark_napumsaka(L("90"));
         }
     } else if last.has_text_in(&[
         "deva", "asura", "Atman", "svarga", "giri", "samudra", "naKa", "keSa", "danta", "stana",
@@ -260,6 +267,9 @@ pub fn run(p: &mut Prakriya) -> Option<()> {
 
     let last = lp.p.get(i_last)?;
     let at = last.has_antya('a');
+    // For krdantas like `pAtra` and `netra`, `last` is just the pratyaya, so also check the full
+    // stem.
+    let stem: String = lp.p.terms().iter().map(|t| t.text.as_str()).collect();
     // adhikAra starts at rule 60 and ends at rule 100.
     if at && last.has_upadha('k') {
         if last.has_text_in(&["cibuka", "SAlUka", "prAtipadika", "aMSuka", "ulmuka"]) {
@@ -369,7 +379,7 @@ pub fn run(p: &mut Prakriya) -> Option<()> {
             lp.mark_pum(L("86"));
         }
     } else if at && last.has_upadha('r') {
-        if last.has_text_in(DVARA_ADI) {
+        if last.has_text_in(DVARA_ADI) || DVARA_ADI.contains(&stem.as_str()) {
             lp.mark_napumsaka(L("90"));
         } else if last.has_text_in(&[
             "cakra",
@@ -68,7 +68,6 @@ pub fn try_pragrhya_rules(p: &mut Prakriya) -> Option<()> {
     Some(())
 }
 
-#[allow(unused)]
 pub fn try_avyaya_rules(p: &mut Prakriya, i: usize) -> Option<()> {
     let t = p.get(i)?;
 
@@ -170,8 +169,11 @@ fn try_run_for_pratipadika_at_index(p: &mut Prakriya, i: usize) -> Option<()> {
         let sup = p.get_if(i_sup, |t| !t.is_lupta())?;
 
         // iyan-uvan are defined in 6.4.77 (Snu-dhAtu-bhruvAm) -- only dhAtu and bhrU apply here.
+        let is_ekac_dhatu_pratipadika =
+            prati.is_ekac_dhatu_pratipadika() && !p.has_prev_non_empty(i, |_| true);
         let iyan_uvan_astri =
-            (prati.has_text("BrU") || prati.is_dhatu()) && !prati.has_text("strI");
+            (prati.has_text("BrU") || prati.is_dhatu() || is_ekac_dhatu_pratipadika)
+                && !prati.has_text("strI");
         let stri_linga = p.has_tag(PT::Stri);
 
         // default: nadI
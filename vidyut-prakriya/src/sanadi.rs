@@ -271,7 +271,7 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
     } else if matches!(sanadi, Some(Ric)) {
         // kArayati, ...
         sp.add("3.1.26", Ric.as_str());
-    } else if base.has_gana(Kandvadi) {
+    } else if base.has_gana(Kandvadi) && base.has_u_in(gana::KANDU_ADI) {
         // kaNDUyati, ...
         //
         // "dvivadhāḥ kaṇḍvādayo, dhātavaḥ prātipādikāni ca. tatra dhātvadhikārād
@@ -279,7 +279,8 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
         //
         // -- KV on 3.1.27.
         //
-        // We avoid `has_u_in` because it turns up too many false positives, e.g. "asu~" (asyati).
+        // We check both the gana and the upadesha. `has_u_in` alone turns up too many false
+        // positives, e.g. "asu~" (asyati).
         sp.add("3.1.27", "yak");
     } else if base.has_u_in(AYADAYA) {
         let mut can_add_pratyaya = true;
@@ -360,7 +361,7 @@ pub fn try_add_optional(p: &mut Prakriya, sanadi: Sanadi) -> Result<()> {
     if matches!(sanadi, Sanadi::yaN | Sanadi::yaNluk) {
         if let Some(t) = p.terms().last() {
             if !(t.is(Sanadi::yaN) && t.is_pratyaya()) {
-                return Err(Error::Abort(p.rule_choices().to_vec()));
+                return Err(p.abort("could not add yaN to this dhatu"));
             }
         }
     }
@@ -309,6 +309,16 @@ pub fn is_hal(c: Sound) -> bool {
 }
 
 /// Converts the sound to its guna replacement, including any "rapara" sounds (1.1.51).
+///
+/// 1.1.2 adeN guRaH
+///
+/// ```
+/// use vidyut_prakriya::to_guna;
+///
+/// assert_eq!(to_guna('i'), Some("e"));
+/// assert_eq!(to_guna('f'), Some("ar"));
+/// assert_eq!(to_guna('k'), None);
+/// ```
 pub fn to_guna(s: Sound) -> Option<&'static str> {
     let res = match s {
         // TODO: remove 'a' | 'A' line
@@ -323,6 +333,16 @@ pub fn to_guna(s: Sound) -> Option<&'static str> {
 }
 
 /// Converts the sound to its vrddhi replacement, including any "rapara" sounds (1.1.51).
+///
+/// 1.1.1 vfdDirAdEc
+///
+/// ```
+/// use vidyut_prakriya::to_vrddhi;
+///
+/// assert_eq!(to_vrddhi('i'), Some("E"));
+/// assert_eq!(to_vrddhi('f'), Some("Ar"));
+/// assert_eq!(to_vrddhi('k'), None);
+/// ```
 pub fn to_vrddhi(s: Sound) -> Option<&'static str> {
     let res = match s {
         'a' | 'A' => "A",
@@ -658,6 +678,122 @@ pub(crate) fn map(keys: &str, values: &str) -> Map {
     map
 }
 
+/// Returns the IPA symbol for `c` without any aspiration.
+fn ipa_base(c: Sound) -> Option<&'static str> {
+    let ret = match c {
+        'a' => "ɐ",
+        'A' => "aː",
+        'i' => "i",
+        'I' => "iː",
+        'u' => "u",
+        'U' => "uː",
+        'f' => "r̩",
+        'F' => "r̩ː",
+        'x' => "l̩",
+        'X' => "l̩ː",
+        'e' => "eː",
+        'E' => "ɐi̯",
+        'o' => "oː",
+        'O' => "ɐu̯",
+        'k' | 'K' => "k",
+        'g' | 'G' => "g",
+        'N' => "ŋ",
+        'c' | 'C' => "c",
+        'j' | 'J' => "ɟ",
+        'Y' => "ɲ",
+        'w' | 'W' => "ʈ",
+        'q' | 'Q' => "ɖ",
+        'R' => "ɳ",
+        't' | 'T' => "t̪",
+        'd' | 'D' => "d̪",
+        'n' => "n̪",
+        'p' | 'P' => "p",
+        'b' | 'B' => "b",
+        'm' => "m",
+        'y' => "j",
+        'r' => "ɾ",
+        'l' => "l",
+        'v' => "ʋ",
+        'S' => "ɕ",
+        'z' => "ʂ",
+        's' => "s",
+        'h' => "ɦ",
+        'H' => "h",
+        'Z' => "x",
+        'V' => "ɸ",
+        _ => return None,
+    };
+    Some(ret)
+}
+
+/// Returns the nasal consonant with the same *sthāna* as `c`, if one exists.
+fn homorganic_nasal(c: Sound) -> Option<&'static str> {
+    let props = SOUND_PROPS.get(&c)?;
+    if props.prayatna != Prayatna::Sprshta {
+        return None;
+    }
+    let ret = match props.sthana.first()? {
+        Sthana::Kantha => "ŋ",
+        Sthana::Talu => "ɲ",
+        Sthana::Murdha => "ɳ",
+        Sthana::Danta => "n̪",
+        Sthana::Oshtha => "m",
+        _ => return None,
+    };
+    Some(ret)
+}
+
+/// Converts SLP1 text to a broad IPA transcription.
+///
+/// - Aspirated stops use `ʰ` if unvoiced and `ʱ` if voiced.
+/// - *anusvāra* becomes the nasal consonant of the following stop. Elsewhere, it nasalizes the
+///   preceding vowel. *anunāsika* vowels (`~`) are likewise nasalized.
+/// - *visarga* becomes `h`, which is distinct from the voiced `ɦ` of *ha*.
+/// - Accent marks and *avagraha* are removed. All other characters are kept as-is.
+///
+/// ```
+/// use vidyut_prakriya::to_ipa;
+///
+/// assert_eq!(to_ipa("Bavati"), "bʱɐʋɐt̪i");
+/// assert_eq!(to_ipa("saMSaya"), "sɐ̃ɕɐjɐ");
+/// assert_eq!(to_ipa("SaNkA"), "ɕɐŋkaː");
+/// assert_eq!(to_ipa("aMka"), "ɐŋkɐ");
+/// ```
+pub fn to_ipa(text: &str) -> String {
+    const NASALIZED: char = '\u{0303}';
+
+    let mut ret = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            'M' => {
+                let nasal = chars.peek().and_then(|n| homorganic_nasal(*n));
+                match nasal {
+                    Some(nasal) => ret.push_str(nasal),
+                    None => ret.push(NASALIZED),
+                }
+            }
+            '~' => ret.push(NASALIZED),
+            '\\' | '^' | '/' | '\'' => (),
+            _ => match ipa_base(c) {
+                Some(base) => {
+                    ret.push_str(base);
+                    if let Some(props) = SOUND_PROPS.get(&c) {
+                        if is_hal(c) && c != 'h' && props.prana == Prana::Mahaprana {
+                            ret.push(match props.ghosha {
+                                Ghosha::Ghoshavat => 'ʱ',
+                                Ghosha::Aghosha => 'ʰ',
+                            });
+                        }
+                    }
+                }
+                None => ret.push(c),
+            },
+        }
+    }
+    ret
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -687,6 +823,30 @@ mod tests {
         assert_eq!(s(&["l", "S", "ku~"]).to_string(), "kKgGNlS");
     }
 
+    #[test]
+    fn test_to_guna_and_to_vrddhi() {
+        let tests = vec![
+            ('i', "e", "E"),
+            ('I', "e", "E"),
+            ('u', "o", "O"),
+            ('U', "o", "O"),
+            ('f', "ar", "Ar"),
+            ('F', "ar", "Ar"),
+            ('x', "al", "Al"),
+        ];
+        for (input, guna, vrddhi) in tests {
+            assert_eq!(to_guna(input), Some(guna), "input: `{input}`");
+            assert_eq!(to_vrddhi(input), Some(vrddhi), "input: `{input}`");
+        }
+
+        // e and o have vrddhi replacements but no guna replacements.
+        assert_eq!(to_vrddhi('e'), Some("E"));
+        assert_eq!(to_vrddhi('o'), Some("O"));
+        assert_eq!(to_guna('e'), None);
+        assert_eq!(to_guna('k'), None);
+        assert_eq!(to_vrddhi('k'), None);
+    }
+
     #[test]
     fn test_map_sounds_jhal_jhash() {
         let actual = map("Jal", "jaS");
@@ -1,7 +1,9 @@
 use crate::args::{Linga, Sup, Vacana, Vibhakti};
+use crate::core::operators as op;
 use crate::core::Prakriya;
 use crate::core::{PrakriyaTag as PT, Term};
 use crate::it_samjna;
+use crate::samjna;
 
 pub fn run(p: &mut Prakriya, linga: Linga, vibhakti: Vibhakti, vacana: Vacana) -> Option<()> {
     let s = Sup::from_args(vibhakti, vacana);
@@ -21,3 +23,23 @@ pub fn run(p: &mut Prakriya, linga: Linga, vibhakti: Vibhakti, vacana: Vacana) -
 
     Some(())
 }
+
+/// Runs rules that delete the sup-pratyaya after an avyaya.
+///
+/// (2.4.82)
+pub fn try_luk_after_avyaya(p: &mut Prakriya) -> Option<()> {
+    let i_sup = p.find_last_where(|t| t.is_sup())?;
+    let i_prati = p.prev_not_empty(i_sup)?;
+
+    // krdantas like `Bojam` and `kftvA` are avyayas only after their pratyaya is known.
+    if p.has(i_prati, |t| t.is_krt()) {
+        samjna::try_avyaya_rules(p, i_prati);
+    }
+
+    if p.has(i_prati, |t| t.is_avyaya()) {
+        // ca, svar, Bojam, kftvA, ...
+        p.run_at("2.4.82", i_sup, op::luk);
+    }
+
+    Some(())
+}
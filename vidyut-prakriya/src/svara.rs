@@ -116,9 +116,9 @@ fn run_at(sp: &mut SvaraPrakriya, i_x: usize) -> Option<SvaraState> {
         None => &temp,
     };
 
-    if (x.has_u("kf\\za~") || x.has_antya('A')) && y.is(K::GaY) {
-        // ka/rzaH
-        sp.mark_antya_udatta("6.1.156", i_y?);
+    if x.is_dhatu() && (x.has_u("kf\\za~") || x.text.contains('A')) && y.is(K::GaY) {
+        // karza/H, pAka/H, tyAga/H
+        sp.mark_antya_udatta("6.1.159", i_y?);
         return Some(SvaraState::Break);
     } else if (x.has_tag(T::zaw) || x.has_u_in(&["tri", "catur"])) && next.has_adi(HAL) {
         if y.has_adi(JHAL) && x.num_vowels() >= 2 {
@@ -189,7 +189,7 @@ fn run_at(sp: &mut SvaraPrakriya, i_x: usize) -> Option<SvaraState> {
     } else if x.is_dhatu() {
         // pa/cati
         // (This is a general case superseded by the rules above.)
-        sp.mark_antya_udatta("6.1.159", i_x);
+        sp.mark_antya_udatta("6.1.162", i_x);
     } else if x.is_pratyaya() {
         if i_x > 0 && x.svara != Some(Anudatta) {
             sp.p.run("6.1.158", |p| {
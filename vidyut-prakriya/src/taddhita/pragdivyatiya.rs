@@ -228,6 +228,15 @@ pub fn run(tp: &mut TaddhitaPrakriya) {
         }
     });
 
+    // 4.1.94 gotrAd yUny astriyAm
+    tp.with_context(Yuvan, |tp| {
+        let prati = tp.prati();
+        if prati.is(yaY) || prati.is(iY) {
+            // gArgyAyaRa, dAkzAyaRa
+            tp.try_add("4.1.101", Pak);
+        }
+    });
+
     tp.with_context(TasyaApatyam, |tp| {
         try_exceptions(tp, "4.1.92");
 
@@ -331,8 +340,14 @@ pub fn run(tp: &mut TaddhitaPrakriya) {
             }
         } else if prati.has_text_in(gana::KALYANI_ADI) {
             tp.try_add_with("4.1.126", Qak, |p| p.set(i_prati, |t| t.set_antya("in")));
-        } else if prati.has_text("kulawA") {
-            tp.optional_try_add_with("4.1.127", Qak, |p| p.set(i_prati, |t| t.set_antya("in")));
+        } else if tp.nyap_pratipadika().has_text("kulawA") {
+            // kOlawineya, kOlaweya
+            let i_start = tp.nyap_pratipadika().start();
+            let added =
+                tp.optional_try_add_with("4.1.127", Qak, |p| p.set(i_start, |t| t.set_antya("in")));
+            if !added {
+                tp.try_add("4.1.120", Qak);
+            }
         } else if prati.has_text("cawakA") {
             // cAwakEra
             tp.try_add("4.1.128", Erak);
@@ -375,6 +390,8 @@ pub fn run(tp: &mut TaddhitaPrakriya) {
         let prati = tp.prati();
         if tp.had_match {
             // Do nothing if any other pratyaya above matches.
+        } else if prati.is(yaY) || prati.is(iY) {
+            // Only the yuvan pratyaya can follow a gotra pratyaya (4.1.93 - 4.1.94).
         } else if prati.has_suffix_in(gana::BAAHU_ADI) {
             // HACK: check suffix instead of uttarapada
             tp.try_add("4.1.96", iY);
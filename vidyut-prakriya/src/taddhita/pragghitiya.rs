@@ -182,4 +182,15 @@ pub fn run(tp: &mut TaddhitaPrakriya) {
             }
         }
     });
+
+    if tp.p.is_chandasi() {
+        let prati = tp.prati();
+        if prati.has_text_in(&["sarva", "deva"]) {
+            // sarvatAti, devatAti
+            tp.try_add("4.4.142", P::tAtil);
+        } else if prati.has_text_in(&["Siva", "Sam", "arizwa"]) {
+            // SivatAti, SantAti, arizwatAti
+            tp.try_add("4.4.143", P::tAtil);
+        }
+    }
 }
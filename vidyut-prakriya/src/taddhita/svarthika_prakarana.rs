@@ -1,6 +1,6 @@
 use crate::args::Taddhita::*;
 use crate::args::TaddhitaArtha::*;
-use crate::core::Rule::Varttika;
+use crate::core::Rule::{Kashika, Varttika};
 use crate::core::Tag as T;
 use crate::ganapatha as gana;
 use crate::taddhita::utils::TaddhitaPrakriya;
@@ -223,5 +223,20 @@ pub fn run(tp: &mut TaddhitaPrakriya) {
         }
     });
 
+    let prati = tp.prati();
+    // HACK: approximate "avyaktAnukaraNa" with stems like `pawat`.
+    if prati.ends_with("at") && prati.num_vowels() >= 2 && tp.try_add("5.4.57", qAc) {
+        // pawat pawat
+        tp.p.run_at(Kashika("5.4.57"), i_prati, |t| {
+            let text = t.text.repeat(2);
+            t.set_text(&text);
+        });
+        // pawapawat
+        tp.p.run_at("6.1.100", i_prati, |t| {
+            let i_t = t.text.len() / 2 - 1;
+            t.text.remove(i_t);
+        });
+    }
+
     // 5.4.68 starts the samAsAnta-prakarana.
 }
@@ -28,9 +28,11 @@ const NAL_PARA: &[&str] = &["Ral", "atus", "us", "Tal", "aTus", "a", "Ral", "va"
 pub fn adesha(p: &mut Prakriya, purusha: Purusha, vacana: Vacana) {
     let pada = if p.has_tag(PT::Parasmaipada) {
         DhatuPada::Parasmai
-    } else {
-        assert!(p.has_tag(PT::Atmanepada));
+    } else if p.has_tag(PT::Atmanepada) {
         DhatuPada::Atmane
+    } else {
+        // No pada was assigned, e.g. because the user disabled 1.3.78.
+        return;
     };
     let tin = Tin::from_args(pada, purusha, vacana);
 
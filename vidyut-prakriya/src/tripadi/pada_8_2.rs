@@ -317,14 +317,15 @@ fn try_lopa_of_samyoganta_and_s(p: &mut Prakriya) {
                 let x = view.upadha()?;
                 let y = view.last().antya()?;
                 if x == 'r' {
-                    if y == 's' {
-                        p.run_at("8.2.24", i, |t| t.set_antya(""));
-                    } else {
+                    // `run_at` fails if the rule has been disabled.
+                    if y != 's' || !p.run_at("8.2.24", i, |t| t.set_antya("")) {
                         break;
                     }
                 } else if HAL.contains(x) && JHAL.contains(y) {
                     // Check "JHAL" to ignore lopa on bahiranga changes like "dadhy atra".
-                    p.run_at("8.2.23", i, |t| t.set_antya(""));
+                    if !p.run_at("8.2.23", i, |t| t.set_antya("")) {
+                        break;
+                    }
                 } else {
                     break;
                 }
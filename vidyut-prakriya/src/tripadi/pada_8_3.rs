@@ -20,12 +20,21 @@ use crate::sounds::{s, Set, AC, HAL, IK, JHAL};
 const IN1: Set = s(&["iR"]);
 const IN2: Set = s(&["iR2"]);
 const IN_KU: Set = s(&["iR2", "ku~"]);
+const NR: Set = Set::from("NR");
 const KU_PU: Set = s(&["ku~", "pu~"]);
 const KHAR: Set = s(&["Kar"]);
 const SHAR: Set = s(&["Sar"]);
 const AA: Set = s(&["a"]);
 const ASH: Set = s(&["aS"]);
 
+/// Basic pratipadikas that end in the *uṇādi pratyaya*s isi~ (2.108 - 2.109), isi~n (2.110 -
+/// 2.114), and usi~ (2.115 - 2.119). When a user passes these stems directly, we don't see the
+/// pratyaya, so list them here.
+const ISI_USI_STEMS: &[&str] = &[
+    "arcis", "Socis", "havis", "sarpis", "Cadis", "Cardis", "barhis", "jyotis", "janus", "arus",
+    "parus", "vapus", "yajus", "tanus", "Danus", "tapus", "Ayus", "cakzus",
+];
+
 fn try_ra_lopa(p: &mut Prakriya) -> Option<()> {
     for i in 0..p.terms().len() {
         let j = p.next_not_empty(i);
@@ -137,6 +146,23 @@ fn try_mn_to_anusvara(p: &mut Prakriya) -> Option<()> {
     Some(())
 }
 
+fn try_add_kuk_tuk_agama(p: &mut Prakriya) -> Option<()> {
+    for i in 0..p.terms().len() {
+        let j = p.next_not_empty(i)?;
+        let x = p.get(i)?;
+        let y = p.get(j)?;
+        if p.is_pada(i) && x.has_antya(NR) && y.has_adi(SHAR) {
+            // prANkzu, sugaRwsu
+            //
+            // By 1.1.46, kuk and wuk are added to the end of the pada.
+            let sub = if x.has_antya('N') { "k" } else { "w" };
+            p.optional_run_at("8.3.28", i, |t| t.text.push_str(sub));
+        }
+    }
+
+    Some(())
+}
+
 fn try_add_dhut_agama(p: &mut Prakriya) -> Option<()> {
     for i in 0..p.terms().len() {
         let j = p.next_not_empty(i)?;
@@ -638,8 +664,19 @@ fn run_shatva_rules_at_char_index(sp: &mut ShaPrakriya) -> Option<()> {
 
     let is_antya = sp.p.next_char_index(&sp.index).is_none();
 
+    // The final `s` of `havis`, `Ayus`, etc. is part of a pratyaya.
+    let t = sp.p.get(i_term)?;
+    let is_basic_is_us_stem = t.is_pratipadika()
+        && !t.is_pratyaya()
+        && t.has_u_in(ISI_USI_STEMS)
+        && i_char + 1 == t.len()
+        && sp.p.has(i_term + 1, |t| t.is_sup());
+
     let term = sp.p.pratyaya(i_term)?;
-    if inku && term.last().has_tag_in(&[T::Pratyaya, T::FlagSaAdeshadi]) && !is_antya {
+    if inku
+        && (term.last().has_tag_in(&[T::Pratyaya, T::FlagSaAdeshadi]) || is_basic_is_us_stem)
+        && !is_antya
+    {
         // For zRus, etc. -- we want to change the first s here, not the second.
         let is_first_s_of_term = i_char == 0;
 
@@ -752,6 +789,10 @@ pub fn run(p: &mut Prakriya) {
         try_mn_to_anusvara(p);
     }
 
+    if set.contains_any("NR") {
+        try_add_kuk_tuk_agama(p);
+    }
+
     if set.contains('q') {
         try_add_dhut_agama(p);
     }
@@ -127,6 +127,17 @@ pub fn run(p: &mut Prakriya) -> Option<()> {
     } else if purva.is_sarvanama() && is_drk_drsha_vatu() {
         // tAdfk, ...
         p.run_at("6.3.91", i_purva, |t| t.set_antya("A"));
+    } else if uttara.has_u("ancu~") && p.has(i_uttara + 1, |t| t.is(K::kvin)) {
+        if purva.has_text_in(&["viSva", "deva"]) {
+            // viSvadryaN, devadryaN
+            p.run_at("6.3.92", i_purva, op::ti("adri"));
+        } else if purva.has_text("sam") {
+            // samyaN
+            p.run_at("6.3.93", i_purva, op::text("sami"));
+        } else if purva.has_text("saha") {
+            // saDryaN
+            p.run_at("6.3.95", i_purva, op::text("saDri"));
+        }
     } else if purva.has_text("ku") && p.is_tatpurusha() {
         if uttara.has_text_in(&["paTin", "akza"]) {
             // kApaTa, kAkza
@@ -201,7 +212,18 @@ pub fn run_after_guna_and_bhasya(p: &mut Prakriya) -> Option<()> {
     let purva = p.get(i_purva)?;
     let uttara = p.get(i_uttara)?;
 
-    if purva.is_upasarga() {
+    if purva.has_text("tiras") && uttara.has_u("ancu~") && p.has(i_uttara + 1, |t| t.is(K::kvin)) {
+        // 6.3.94 applies only if the `a` of `ac` is kept. But if the anga is bha, 6.4.138 deletes
+        // it. So, tiryaN but tiraScaH.
+        let is_bha = p.has(i_uttara + 1, |t| t.has_tag(T::Bha));
+        if !is_bha {
+            p.run_at("6.3.94", i_purva, op::text("tiri"));
+        }
+    } else if uttara.has_text("c") && uttara.has_u("ancu~") {
+        // daDIcaH, pratIcaH, ...
+        let dirgha = al::to_dirgha(purva.antya()?)?;
+        p.run_at("6.3.138", i_purva, |t| t.set_antya_char(dirgha));
+    } else if purva.is_upasarga() {
         if p.has(i_uttara + 1, |t| t.is(K::GaY)) {
             // rule is "bahulam"
             if purva.is(U::ni) && uttara.has_u_in(&["vfN", "vfY"]) {
@@ -219,9 +241,6 @@ pub fn run_after_guna_and_bhasya(p: &mut Prakriya) -> Option<()> {
                 p.run_at("6.3.124", i_purva, |t| t.set_antya_char(sub));
             }
         }
-    } else if uttara.has_text("c") && uttara.has_u("ancu~") {
-        let dirgha = al::to_dirgha(purva.antya()?)?;
-        p.run_at("6.3.138", i_purva, |t| t.set_antya_char(dirgha));
     } else if uttara.has_text("citi") && p.has(i_uttara + 1, |t| t.is(D::kap)) {
         // citIka
         p.run_at("6.3.125", i_uttara, |t| t.set_antya_char('I'));
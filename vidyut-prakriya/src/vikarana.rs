@@ -246,7 +246,8 @@ fn maybe_replace_cli_with_cin(p: &mut Prakriya, i: usize) -> Option<()> {
         {
             // adIpi, ajani, aboDi, ...
             p.optional_run("3.1.61", to_cin);
-        } else if p.has_tag(PT::Karmani) {
+        } else if p.is_bhave_or_karmani() {
+            // aBAvi, akAri, ...
             p.run("3.1.66", to_cin);
         }
     }
@@ -267,7 +268,9 @@ fn maybe_replace_cli_with_sic(p: &mut Prakriya, i: usize) {
 
 /// Applies the vikarana rules for luN (3.1.43 - 3.1.66).
 fn add_lun_vikarana(p: &mut Prakriya) {
-    p.run("3.1.43", add_vikarana(cli));
+    if !p.run("3.1.43", add_vikarana(cli)) {
+        return;
+    }
 
     let n = p.terms().len();
     assert!(n >= 3);
@@ -4,11 +4,22 @@
 //! how words are derived in the system.
 //!
 //! For more detailed control over in constructing `Vyakarana`, see `VyakaranaBuilder`.
-use crate::args::{Dhatu, Krdanta, Pada, Pratipadika, Samasa, Subanta, Taddhitanta, Tinanta};
+use crate::args::{
+    BaseKrt, Dhatu, Krdanta, Lakara, Linga, Pada, Pratipadika, Prayoga, Purusha, Samasa, Subanta,
+    Taddhita, Taddhitanta, Tinanta, Vacana, Vibhakti,
+};
 use crate::ashtadhyayi;
-use crate::core::prakriya_stack::PrakriyaStack;
-use crate::core::Prakriya;
+use crate::caching::{calculate_hash, Cache, LruCache};
+use crate::core::prakriya_stack::{OptionalMode, PrakriyaStack};
 use crate::core::PrakriyaTag as PT;
+use crate::core::{LogSteps, Prakriya, Rule, RuleChoice, Term};
+use core::cell::RefCell;
+use core::hash::Hash;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// The number of dhatus or pratipadikas whose available pratyayas we remember.
+const AVAILABLE_CACHE_SIZE: usize = 32;
 
 /// An interface to the Ashtadhyayi and its related works.
 ///
@@ -37,15 +48,55 @@ use crate::core::PrakriyaTag as PT;
 ///     .use_svaras(true)
 ///     .build();
 /// ```
+///
+/// ### Result ordering
+///
+/// The `derive_*` methods return their results in a stable order that depends only on the input
+/// and on the configuration of this struct. The first result is the derivation that accepts every
+/// optional rule it encounters. Subsequent results come from declining those optional rules,
+/// starting with the last optional rule applied and working backward. So equal inputs always
+/// produce the same `Vec<Prakriya>` in the same order.
+///
+/// ### Thread safety
+///
+/// `Vyakarana` is `Send + Sync`, and all `derive_*` methods take `&self`. So a single instance can
+/// be wrapped in an `Arc` and shared across threads:
+///
+/// ```
+/// # use vidyut_prakriya::*;
+/// # use vidyut_prakriya::args::*;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let v = Arc::new(Vyakarana::new());
+/// let args = Tinanta::builder()
+///     .dhatu(Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi))
+///     .prayoga(Prayoga::Kartari)
+///     .purusha(Purusha::Prathama)
+///     .vacana(Vacana::Eka)
+///     .lakara(Lakara::Lat)
+///     .build()?;
+///
+/// let handle = {
+///     let v = Arc::clone(&v);
+///     thread::spawn(move || v.derive_tinantas(&args))
+/// };
+/// let prakriyas = handle.join().expect("thread should not panic");
+/// assert_eq!(prakriyas[0].text(), "Bavati");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// Internally, we cache some intermediate results (e.g. prepared dhatus) to speed up repeated
+/// derivations. These caches are thread-local, so threads never contend on a lock and never share
+/// mutable state. The optional results cache (see `VyakaranaBuilder::cache_capacity`) is the
+/// exception: it is shared by all threads that use this `Vyakarana` and is guarded by a lock.
 #[derive(Debug, Default)]
 pub struct Vyakarana {
     // Options we hope to add in the future:
     // - `svara`    -- if set, enable accent rules.
     // - `extended` -- if set, enable rare rules that are less useful, such as 8.4.48 (aco
     //   rahAbhyAM dve), which creates words like *kAryyate*, *brahmmA*, etc.
-    // - `disable`  -- if set, disable the rules provided. To implement this, we should make
-    //   `Prakriya::step` private and add a check statement in `Prakriya::op`.
-    log_steps: bool,
+    log_steps: LogSteps,
     // If set, also generate chaandasa forms.
     is_chandasi: bool,
     // If set, use svara rules. If unset, output will have no svaras.
@@ -53,17 +104,120 @@ pub struct Vyakarana {
     // If set, preserve the final `s` and `r` of a pada, since these are important to preserve for
     // certain NLP use cases.
     nlp_mode: bool,
+    // Which optional derivations to return.
+    optional_mode: OptionalMode,
+    // If set, return failed derivations when no derivation succeeds.
+    explain_failures: bool,
+    // Rules that derivations should never apply.
+    disabled_rules: Vec<Rule>,
+    // Decisions that derivations must make for the given optional rules.
+    rule_choices: Vec<RuleChoice>,
+    // If set, cache the results of `derive_*` calls.
+    results_cache: Option<Mutex<ResultsCache>>,
+}
+
+/// Hit and miss counts for the results cache of some `Vyakarana`.
+///
+/// For details, see `VyakaranaBuilder::cache_capacity`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CacheStats {
+    hits: u64,
+    misses: u64,
+}
+
+impl CacheStats {
+    /// The number of `derive_*` calls that were served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// The number of `derive_*` calls that were not in the cache and had to be derived.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+/// The *tiṅanta*s for some *dhātu*, *prayoga*, and *lakāra*, grouped by *puruṣa* and *vacana*.
+///
+/// For details, see `Vyakarana::derive_all_lakaras`.
+#[derive(Clone, Debug, Default)]
+pub struct TinantaTable {
+    cells: BTreeMap<(Purusha, Vacana), Vec<Prakriya>>,
+}
+
+impl TinantaTable {
+    /// Returns the prakriyas for the given *puruṣa* and *vacana*.
+    ///
+    /// Results are in the same order as for `Vyakarana::derive_tinantas`.
+    pub fn get(&self, purusha: Purusha, vacana: Vacana) -> &[Prakriya] {
+        match self.cells.get(&(purusha, vacana)) {
+            Some(prakriyas) => prakriyas,
+            None => &[],
+        }
+    }
+
+    /// Returns the text of each result for the given *puruṣa* and *vacana*.
+    pub fn texts(&self, purusha: Purusha, vacana: Vacana) -> Vec<String> {
+        self.get(purusha, vacana).iter().map(|p| p.text()).collect()
+    }
+
+    /// Returns whether this table has no results.
+    pub fn is_empty(&self) -> bool {
+        self.cells.values().all(|prakriyas| prakriyas.is_empty())
+    }
 }
 
+/// The arguments to some `derive_*` call.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum CacheKey {
+    Dhatu(Dhatu),
+    Tinanta(Tinanta),
+    Subanta(Subanta),
+    Krdanta(Krdanta),
+    Taddhitanta(Taddhitanta),
+    Stryanta(Pratipadika),
+    Samasa(Samasa),
+    Vakya(Vec<Pada>),
+}
+
+/// An LRU cache from the arguments of some `derive_*` call to its results.
+#[derive(Debug)]
+struct ResultsCache {
+    items: LruCache<CacheKey, Vec<Prakriya>>,
+    stats: CacheStats,
+}
+
+// `Vyakarana` must stay shareable across threads. (See "Thread safety" above.)
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Vyakarana>();
+};
+
 // TODO: better error handling.
 impl Vyakarana {
     /// Creates a basic interface with sane defaults.
     pub fn new() -> Self {
         Vyakarana {
-            log_steps: true,
+            log_steps: LogSteps::Full,
             is_chandasi: false,
             use_svaras: false,
             nlp_mode: false,
+            optional_mode: OptionalMode::All,
+            explain_failures: false,
+            disabled_rules: Vec::new(),
+            rule_choices: Vec::new(),
+            results_cache: None,
+        }
+    }
+
+    /// Returns hit and miss counts for this struct's results cache.
+    ///
+    /// If the cache is disabled, both counts are zero. For details, see
+    /// `VyakaranaBuilder::cache_capacity`.
+    pub fn cache_stats(&self) -> CacheStats {
+        match &self.results_cache {
+            Some(cache) => cache.lock().map(|c| c.stats).unwrap_or_default(),
+            None => CacheStats::default(),
         }
     }
 
@@ -165,9 +319,14 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ````
     pub fn derive_dhatus(&self, args: &Dhatu) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_dhatu(p, args));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Dhatu(args.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_dhatu(p, args));
+                stack.prakriyas()
+            },
+        )
     }
 
     /// Returns all possible tinanta prakriyas that can be derived with the given initial
@@ -319,26 +478,86 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_tinantas(&self, args: &Tinanta) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        // TODO: handle error properly.
-        stack.find_all(|p| ashtadhyayi::derive_tinanta(p, args));
-        let mut prakriyas = stack.prakriyas();
+        use crate::args::DhatuPada;
 
         // If the caller specified an explicit pada, keep only the results that match that pada.
         //
+        // We reject other results within the stack so that `OptionalMode::PreferredOnly` can
+        // continue searching for a result with the requested pada.
+        //
         // TODO: to avoid wasting time on deriving words that we'll just throw out, push this
         // further into `derive_tinanta`.
-        if let Some(pada) = args.pada() {
-            use crate::args::DhatuPada;
-            prakriyas.retain(|p| match pada {
-                DhatuPada::Parasmai => {
-                    p.has_tag(pada.as_tag().into()) && !p.has_tag(PT::AmAtmanepada)
+        let has_pada = |p: &Prakriya| match args.pada() {
+            Some(DhatuPada::Parasmai) => {
+                p.has_tag(PT::Parasmaipada) && !p.has_tag(PT::AmAtmanepada)
+            }
+            Some(DhatuPada::Atmane) => p.has_tag_in(&[PT::Atmanepada, PT::AmAtmanepada]),
+            None => true,
+        };
+
+        self.with_cache(
+            || CacheKey::Tinanta(args.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                // TODO: handle error properly.
+                stack.find_all(|p| {
+                    ashtadhyayi::derive_tinanta(p, args)?;
+                    if has_pada(p) {
+                        Ok(())
+                    } else {
+                        Err(p.abort("this dhatu does not use the requested pada"))
+                    }
+                });
+                stack.prakriyas()
+            },
+        )
+    }
+
+    /// Returns the full *tiṅanta* table of `dhatu` for each *lakāra*.
+    ///
+    /// This is a convenience wrapper over calling `derive_tinantas` for each combination of
+    /// *lakāra*, *puruṣa*, and *vacana*. Results for both *pada*s are included. We skip *leṭ*
+    /// unless `is_chandasi` is set, and we omit any *lakāra* that has no results.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula(Slp1String::from("BU")?, Gana::Bhvadi);
+    /// let tables = v.derive_all_lakaras(&bhu, Prayoga::Kartari);
+    /// let lat = &tables[&Lakara::Lat];
+    /// assert_eq!(lat.texts(Purusha::Prathama, Vacana::Eka), vec!["Bavati"]);
+    /// assert_eq!(lat.texts(Purusha::Uttama, Vacana::Bahu), vec!["BavAmaH"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn derive_all_lakaras(
+        &self,
+        dhatu: &Dhatu,
+        prayoga: Prayoga,
+    ) -> BTreeMap<Lakara, TinantaTable> {
+        let mut ret = BTreeMap::new();
+        for lakara in Lakara::iter() {
+            if lakara == Lakara::Let && !self.is_chandasi {
+                continue;
+            }
+
+            let mut table = TinantaTable::default();
+            for purusha in Purusha::iter() {
+                for vacana in Vacana::iter() {
+                    let args = Tinanta::new(dhatu.clone(), prayoga, lakara, purusha, vacana);
+                    let prakriyas = self.derive_tinantas(&args);
+                    if !prakriyas.is_empty() {
+                        table.cells.insert((purusha, vacana), prakriyas);
+                    }
                 }
-                DhatuPada::Atmane => p.has_tag_in(&[pada.as_tag().into(), PT::AmAtmanepada]),
-            });
+            }
+            if !table.is_empty() {
+                ret.insert(lakara, table);
+            }
         }
-
-        prakriyas
+        ret
     }
 
     /// Returns all possible subanta prakriyas that can be derived with the given initial
@@ -361,9 +580,40 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_subantas(&self, subanta: &Subanta) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_subanta(p, subanta));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Subanta(subanta.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_subanta(p, subanta));
+                stack.prakriyas()
+            },
+        )
+    }
+
+    /// Returns all prakriyas for the citation form of `pratipadika` in the given `linga`.
+    ///
+    /// The citation form is the *prathamā ekavacana*, which dictionaries and lexicons usually use
+    /// as a headword. This method is a shorthand for calling `derive_subantas` with those values.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let deva = Pratipadika::basic(Slp1String::from("deva")?);
+    /// let prakriyas = v.citation_form(deva, Linga::Pum);
+    /// assert_eq!(prakriyas[0].text(), "devaH");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn citation_form(
+        &self,
+        pratipadika: impl Into<Pratipadika>,
+        linga: Linga,
+    ) -> Vec<Prakriya> {
+        let args = Subanta::new(pratipadika, linga, Vibhakti::Prathama, Vacana::Eka);
+        self.derive_subantas(&args)
     }
 
     /// Returns all possible krdanta prakriyas that can be derived with the given initial
@@ -398,9 +648,14 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_krdantas(&self, krdanta: &Krdanta) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_krdanta(p, krdanta));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Krdanta(krdanta.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_krdanta(p, krdanta));
+                stack.prakriyas()
+            },
+        )
     }
 
     /// Returns all possible *taddhitānta prakriyā*s that can be derived with the given initial
@@ -422,9 +677,14 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_taddhitantas(&self, spec: &Taddhitanta) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_taddhitanta(p, spec));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Taddhitanta(spec.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_taddhitanta(p, spec));
+                stack.prakriyas()
+            },
+        )
     }
 
     /// (Experimental) Returns all possible stryanta prakriyas that can be derived with the given
@@ -442,9 +702,14 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_stryantas(&self, pratipadika: &Pratipadika) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_stryanta(p, pratipadika));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Stryanta(pratipadika.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_stryanta(p, pratipadika));
+                stack.prakriyas()
+            },
+        )
     }
 
     /// Returns all possible sandhi results that follow from the given initial conditions.
@@ -473,27 +738,256 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_samasas(&self, args: &Samasa) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_samasa(p, args));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Samasa(args.clone()),
+            || {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_samasa(p, args));
+                stack.prakriyas()
+            },
+        )
     }
 
     /// (Experimental) Returns all possible sandhi results that follow from the given initial
     /// conditions.
     ///
+    /// Each `Pada::Subanta` and `Pada::Tinanta` is derived first, and we join each combination of
+    /// their results with external sandhi. A final visarga is kept only at the end of the
+    /// sentence. Use [`Prakriya::vakya_text`] to get the result with spaces between padas.
+    ///
     ///
     /// ### Example
     ///
     /// ```
     /// # use vidyut_prakriya::*;
     /// # use vidyut_prakriya::args::*;
-    ///
     /// let v = Vyakarana::new();
+    ///
+    /// let rama = Pratipadika::basic(Slp1String::from("rAma")?);
+    /// let vana = Pratipadika::basic(Slp1String::from("vana")?);
+    /// let gam = Dhatu::mula(Slp1String::from("ga\\mx~")?, Gana::Bhvadi);
+    /// let padas = [
+    ///     Subanta::new(rama, Linga::Pum, Vibhakti::Prathama, Vacana::Eka).into(),
+    ///     Subanta::new(vana, Linga::Napumsaka, Vibhakti::Dvitiya, Vacana::Eka).into(),
+    ///     Tinanta::builder()
+    ///         .dhatu(gam)
+    ///         .prayoga(Prayoga::Kartari)
+    ///         .purusha(Purusha::Prathama)
+    ///         .vacana(Vacana::Eka)
+    ///         .lakara(Lakara::Lat)
+    ///         .build()?
+    ///         .into(),
+    /// ];
+    /// let prakriyas = v.derive_vakyas(&padas);
+    /// assert_eq!(prakriyas[0].vakya_text(), "rAmo vanaM gacCati");
     /// # Ok::<(), Error>(())
+    /// ```
     pub fn derive_vakyas(&self, padas: &[Pada]) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_vakya(p, padas));
-        stack.prakriyas()
+        self.with_cache(
+            || CacheKey::Vakya(padas.to_vec()),
+            || {
+                // Derive each pada separately, then join each combination of results.
+                let mut combinations: Vec<Vec<Term>> = vec![Vec::new()];
+                for pada in padas {
+                    let options = ashtadhyayi::prepare_vakya_padas(pada, self.create_pada_stack());
+                    combinations = combinations
+                        .iter()
+                        .flat_map(|prefix| {
+                            options.iter().map(move |option| {
+                                let mut combination = prefix.clone();
+                                combination.push(option.clone());
+                                combination
+                            })
+                        })
+                        .collect();
+                }
+
+                let mut ret = Vec::new();
+                for combination in &combinations {
+                    let mut stack = self.create_prakriya_stack();
+                    stack.find_all(|p| ashtadhyayi::derive_vakya(p, combination));
+                    ret.extend(stack.prakriyas());
+                }
+                ret
+            },
+        )
+    }
+
+    /// (Experimental) Joins two already-derived words with sandhi and returns all possible
+    /// results.
+    ///
+    /// `left` and `right` are usually SLP1 strings. If `left` is a string that ends in a visarga,
+    /// that visarga could have come from either `s` or `r`, so we return the results for both
+    /// (`rAmogacCati` and `rAmargacCati`). To get just one, pass `rAmas` or `punar`. Each result joins both words without a space, e.g.
+    /// `rAmogacCati`. Results are deduplicated and use the order described in [`Vyakarana`].
+    ///
+    /// Some sandhi rules depend on morphology that a plain string doesn't have. For example, a
+    /// *dvivacana* that ends in *ī*, *ū*, or *e* is *pragṛhya* (1.1.11) and blocks vowel sandhi.
+    /// For these cases, pass a `Pada` with full morphology instead.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// assert_eq!(v.sandhi("na", "asti"), vec!["nAsti"]);
+    ///
+    /// // harI etO (1.1.11)
+    /// let hari = Pratipadika::basic(Slp1String::from("hari")?);
+    /// let harI = Subanta::new(hari, Linga::Pum, Vibhakti::Prathama, Vacana::Dvi);
+    /// assert_eq!(v.sandhi(harI, "etO"), vec!["harIetO"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn sandhi(&self, left: impl Into<Pada>, right: impl Into<Pada>) -> Vec<String> {
+        let right = right.into();
+        let lefts = match left.into() {
+            // A final visarga could have come from either `s` or `r`, so try both.
+            Pada::Dummy(text) => match text.strip_suffix('H') {
+                Some(prefix) => vec![
+                    Pada::from_text(format!("{prefix}s")),
+                    Pada::from_text(format!("{prefix}r")),
+                ],
+                None => vec![Pada::Dummy(text)],
+            },
+            pada => vec![pada],
+        };
+
+        let mut ret: Vec<String> = Vec::new();
+        for left in lefts {
+            let padas = [left, right.clone()];
+            for p in self.derive_vakyas(&padas) {
+                let text = p.text();
+                if !ret.contains(&text) {
+                    ret.push(text);
+                }
+            }
+        }
+        ret
+    }
+
+    /// Returns all ordinary *kṛt pratyaya*s that produce at least one result for the given
+    /// `dhatu`.
+    ///
+    /// We find these *pratyaya*s by trying to derive a *kṛdanta* with each `BaseKrt` in turn. We
+    /// don't specify an *upapada* or *artha*, so *pratyaya*s that require these conditions will
+    /// not be returned. Results are cached per dhatu.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let kr = Dhatu::mula(Slp1String::from("qukf\\Y")?, Gana::Tanadi);
+    /// let krts = v.available_krts(&kr);
+    /// assert!(krts.contains(&BaseKrt::tfc));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn available_krts(&self, dhatu: &Dhatu) -> Vec<BaseKrt> {
+        thread_local! {
+            static CACHE: RefCell<Cache<(u64, bool, u64), Vec<BaseKrt>>> = RefCell::new(Cache::new(AVAILABLE_CACHE_SIZE));
+        };
+
+        let cache_key = (
+            calculate_hash(dhatu),
+            self.is_chandasi,
+            calculate_hash(&self.disabled_rules),
+        );
+        if let Some(ret) = CACHE.with(|cache| cache.borrow_mut().read(&cache_key).cloned()) {
+            return ret;
+        }
+
+        let ret: Vec<_> = BaseKrt::iter()
+            .filter(|krt| {
+                let args = Krdanta::new(dhatu.clone(), *krt);
+                let mut stack = self.create_trial_stack();
+                stack.find_all(|p| ashtadhyayi::derive_krdanta(p, &args));
+                !stack.prakriyas().is_empty()
+            })
+            .collect();
+
+        CACHE.with(|cache| cache.borrow_mut().write(cache_key, ret.clone()));
+        ret
+    }
+
+    /// Returns all *taddhita pratyaya*s that produce at least one result for the given
+    /// `pratipadika`.
+    ///
+    /// We find these *pratyaya*s by trying to derive a *taddhitānta* with each `Taddhita` in turn.
+    /// We don't specify an *artha*, so any meaning condition is allowed. Results are cached per
+    /// pratipadika.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let upagu = Pratipadika::basic(Slp1String::from("upagu")?);
+    /// let taddhitas = v.available_taddhitas(&upagu);
+    /// assert!(taddhitas.contains(&Taddhita::aR));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn available_taddhitas(&self, pratipadika: &Pratipadika) -> Vec<Taddhita> {
+        thread_local! {
+            static CACHE: RefCell<Cache<(u64, bool, u64), Vec<Taddhita>>> = RefCell::new(Cache::new(AVAILABLE_CACHE_SIZE));
+        };
+
+        let cache_key = (
+            calculate_hash(pratipadika),
+            self.is_chandasi,
+            calculate_hash(&self.disabled_rules),
+        );
+        if let Some(ret) = CACHE.with(|cache| cache.borrow_mut().read(&cache_key).cloned()) {
+            return ret;
+        }
+
+        let ret: Vec<_> = Taddhita::iter()
+            .filter(|taddhita| {
+                let args = Taddhitanta::new(pratipadika.clone(), *taddhita);
+                let mut stack = self.create_trial_stack();
+                stack.find_all(|p| ashtadhyayi::derive_taddhitanta(p, &args));
+                !stack.prakriyas().is_empty()
+            })
+            .collect();
+
+        CACHE.with(|cache| cache.borrow_mut().write(cache_key, ret.clone()));
+        ret
+    }
+
+    /// Returns the cached results for `key` if they exist. Otherwise, runs `derive` and caches its
+    /// results.
+    ///
+    /// `make_key` is called only if the cache is enabled.
+    fn with_cache(
+        &self,
+        make_key: impl FnOnce() -> CacheKey,
+        derive: impl FnOnce() -> Vec<Prakriya>,
+    ) -> Vec<Prakriya> {
+        let cache = match &self.results_cache {
+            Some(cache) => cache,
+            None => return derive(),
+        };
+
+        let key = make_key();
+        if let Ok(mut c) = cache.lock() {
+            if let Some(ret) = c.items.read(&key).cloned() {
+                c.stats.hits += 1;
+                return ret;
+            }
+            c.stats.misses += 1;
+        }
+
+        // Don't hold the lock while deriving so that other threads can use the cache.
+        let ret = derive();
+        if let Ok(mut c) = cache.lock() {
+            c.items.write(key, ret.clone());
+        }
+        ret
     }
 
     /// Creates a prakriya stack that generates prakriyas according to our derivation options.
@@ -504,6 +998,32 @@ impl Vyakarana {
             self.use_svaras,
             self.nlp_mode,
         )
+        .with_optional_mode(self.optional_mode)
+        .with_explain_failures(self.explain_failures)
+        .with_disabled_rules(&self.disabled_rules)
+        .with_fixed_choices(&self.rule_choices)
+    }
+
+    /// Creates a prakriya stack for deriving the padas of a vakya.
+    ///
+    /// We join these padas in a separate derivation, so we don't need their history.
+    fn create_pada_stack(&self) -> PrakriyaStack {
+        PrakriyaStack::new(
+            LogSteps::None,
+            self.is_chandasi,
+            self.use_svaras,
+            self.nlp_mode,
+        )
+        .with_optional_mode(self.optional_mode)
+        .with_disabled_rules(&self.disabled_rules)
+    }
+
+    /// Creates a prakriya stack for checking whether some derivation succeeds.
+    ///
+    /// We don't need history or svaras for these checks, so we disable them for speed.
+    fn create_trial_stack(&self) -> PrakriyaStack {
+        PrakriyaStack::new(LogSteps::None, self.is_chandasi, false, self.nlp_mode)
+            .with_disabled_rules(&self.disabled_rules)
     }
 }
 
@@ -522,14 +1042,18 @@ impl VyakaranaBuilder {
 
     /// *(default: true)* Controls whether or not to log individual steps of the prakriya.
     ///
-    /// - If `true`, each `Prakriya` will contain a full history, but the program will run more
-    ///   slowly. We recommend this setting for most use cases.
+    /// - If `true` (or `LogSteps::Full`), each `Prakriya` will contain a full history, but the
+    ///   program will run more slowly. We recommend this setting for most use cases.
     ///
-    /// - If `false`, the program will run faster, but only the final output of the `Prakriya` will
-    ///   be available. This is best used when you want to generate a word list and don't need the
-    ///   underlying derivation.
-    pub fn log_steps(mut self, value: bool) -> Self {
-        self.vyakarana.log_steps = value;
+    /// - If `LogSteps::RulesOnly`, each `Prakriya` will record the rules it applied but not the
+    ///   state of the derivation after each rule. This is best used for bulk jobs that need rule
+    ///   provenance but not the full derivation.
+    ///
+    /// - If `false` (or `LogSteps::None`), the program will run faster, but only the final output
+    ///   of the `Prakriya` will be available. This is best used when you want to generate a word
+    ///   list and don't need the underlying derivation.
+    pub fn log_steps(mut self, value: impl Into<LogSteps>) -> Self {
+        self.vyakarana.log_steps = value.into();
         self
     }
 
@@ -564,6 +1088,118 @@ impl VyakaranaBuilder {
         self
     }
 
+    /// *(default: `OptionalMode::All`)* Controls which optional derivations to return.
+    ///
+    /// - If `OptionalMode::All`, return a result for every combination of optional rules.
+    ///
+    /// - If `OptionalMode::PreferredOnly`, return at most one result per input, namely the one
+    ///   that accepts every optional rule it can. This is the first result that `All` would
+    ///   return.
+    pub fn optional_mode(mut self, value: OptionalMode) -> Self {
+        self.vyakarana.optional_mode = value;
+        self
+    }
+
+    /// *(default: false)* Controls whether to explain why a derivation produced no results.
+    ///
+    /// - If `true` and no derivation succeeds, each `derive_*` method returns the derivations
+    ///   that failed instead of an empty `Vec`. For each of these, `Prakriya::failure` describes
+    ///   why the derivation stopped, and `Prakriya::history` shows the last rules it applied.
+    ///
+    /// - If `false`, failed derivations are discarded.
+    pub fn explain_failures(mut self, value: bool) -> Self {
+        self.vyakarana.explain_failures = value;
+        self
+    }
+
+    /// *(default: none)* *(advanced)* Prevents derivations from applying the given rules.
+    ///
+    /// This option is meant for research, e.g. to see which derivations depend on some rule. An
+    /// optional rule that is disabled is always declined. If a derivation reaches a required rule
+    /// that is disabled, the derivation fails, since later rules assume that earlier rules have
+    /// applied. With `explain_failures`, such a derivation reports the disabled rule in
+    /// `Prakriya::failure`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// let v = Vyakarana::builder().disable_rules(&[Rule::from("6.1.101")]).build();
+    /// assert!(v.sandhi("na", "asti").is_empty());
+    /// assert_eq!(v.sandhi("na", "iti"), vec!["neti"]);
+    /// ```
+    pub fn disable_rules(mut self, rules: &[Rule]) -> Self {
+        self.vyakarana.disabled_rules = rules.to_vec();
+        self
+    }
+
+    /// *(default: none)* *(advanced)* Makes derivations use the given decision for each optional
+    /// rule in `choices`.
+    ///
+    /// Other optional rules are explored as usual. Choices for rules that a derivation never
+    /// reaches have no effect. Together with `Prakriya::rule_choices`, this lets callers replay a
+    /// specific branch of some derivation, e.g. the *seṭ* or *aniṭ* form of a *veṭ* root.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let gah = Dhatu::mula(Slp1String::from("gAhU~\\")?, Gana::Bhvadi);
+    /// let args = Tinanta::builder()
+    ///     .dhatu(gah)
+    ///     .prayoga(Prayoga::Kartari)
+    ///     .purusha(Purusha::Prathama)
+    ///     .vacana(Vacana::Eka)
+    ///     .lakara(Lakara::Lrt)
+    ///     .build()?;
+    ///
+    /// // 7.2.44 optionally blocks iṭ-āgama.
+    /// let seT = RuleChoice::new("7.2.44", Decision::Decline);
+    /// let v = Vyakarana::builder().rule_choices(&[seT]).build();
+    /// let padas: Vec<_> = v.derive_tinantas(&args).iter().map(|p| p.text()).collect();
+    /// assert_eq!(padas, vec!["gAhizyate"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn rule_choices(mut self, choices: &[RuleChoice]) -> Self {
+        self.vyakarana.rule_choices = choices.to_vec();
+        self
+    }
+
+    /// *(default: 0)* Sets how many `derive_*` results to cache.
+    ///
+    /// - If greater than 0, each `derive_*` method stores its results in an LRU cache keyed by
+    ///   its arguments. Repeated calls with the same arguments return a copy of the cached
+    ///   results without running the derivation again. Once the cache is full, each new entry
+    ///   evicts the least recently used one. Memory is allocated only as entries are added, so
+    ///   `value` is just an upper bound. This is best used for long-running servers that see many
+    ///   repeated queries. Use `Vyakarana::cache_stats` to check how well the cache is working.
+    ///
+    /// - If 0, results are not cached.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// let v = Vyakarana::builder().cache_capacity(1024).build();
+    /// assert_eq!(v.sandhi("na", "asti"), vec!["nAsti"]);
+    /// assert_eq!(v.cache_stats().misses(), 1);
+    ///
+    /// assert_eq!(v.sandhi("na", "asti"), vec!["nAsti"]);
+    /// assert_eq!(v.cache_stats().hits(), 1);
+    /// ```
+    pub fn cache_capacity(mut self, value: usize) -> Self {
+        self.vyakarana.results_cache = if value > 0 {
+            Some(Mutex::new(ResultsCache {
+                items: LruCache::new(value),
+                stats: CacheStats::default(),
+            }))
+        } else {
+            None
+        };
+        self
+    }
+
     /// Creates an `Vyakarana` struct.
     pub fn build(self) -> Vyakarana {
         self.vyakarana
@@ -16,7 +16,6 @@ Useful links:
 - wasm-bindgen book: https://rustwasm.github.io/wasm-bindgen/introduction.html
 */
 use crate::args::*;
-use crate::core::Rule;
 use crate::core::{Prakriya, Step, StepTerm};
 use crate::dhatupatha::Dhatupatha;
 use serde::{Deserialize, Serialize};
@@ -81,23 +80,6 @@ pub struct WebPrakriya {
     history: Vec<WebStep>,
 }
 
-impl Rule {
-    /// The text this rule comes from.
-    fn source(&self) -> &str {
-        use Rule::*;
-        match self {
-            Ashtadhyayi(_) => "ashtadhyayi",
-            Varttika(_) => "varttika",
-            Dhatupatha(_) => "dhatupatha",
-            Kashika(_) => "kashika",
-            Linganushasana(_) => "linganushasanam",
-            Kaumudi(_) => "kaumudi",
-            Unadipatha(_) => "unadi",
-            Phit(_) => "phit",
-        }
-    }
-}
-
 /// Converts the native `Step` array to a format that wasm_bindgen can serialize.
 fn to_web_history(history: &[Step]) -> Vec<WebStep> {
     history
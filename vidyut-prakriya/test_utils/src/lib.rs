@@ -520,6 +520,8 @@ test_tin!(assert_has_iw_k, Karmani, P::Uttama, Eka, Atmane);
 test_tin!(assert_has_vahi_k, Karmani, P::Uttama, Dvi, Atmane);
 test_tin!(assert_has_mahin_k, Karmani, P::Uttama, Bahu, Atmane);
 
+test_tin!(assert_has_ta_bhave, Bhave, P::Prathama, Eka, Atmane);
+
 macro_rules! test_la {
     ($fn_name:ident, $la:expr) => {
         pub fn $fn_name(prefixes: &[&str], dhatu: &Dhatu, expected: &[&str]) {
@@ -11,7 +11,9 @@ use test_utils::assert_has_results;
 use vidyut_prakriya::args::BaseKrt as Krt;
 use vidyut_prakriya::args::Gana::*;
 use vidyut_prakriya::args::*;
-use vidyut_prakriya::Vyakarana;
+use vidyut_prakriya::{
+    CacheStats, Decision, LogSteps, OptionalMode, Prakriya, Rule, RuleChoice, Samjna, Vyakarana,
+};
 
 /// Checks that Enum string representations exist and are 1:1.
 #[test]
@@ -24,6 +26,10 @@ fn enum_as_str_and_from_string() {
         assert_eq!(val, val.as_str().parse().expect("ok"));
     }
 
+    for val in KrtArtha::iter() {
+        assert_eq!(val, val.as_str().parse().expect("ok"));
+    }
+
     for val in Lakara::iter() {
         assert_eq!(val, val.as_str().parse().expect("ok"));
     }
@@ -61,6 +67,17 @@ fn enum_as_str_and_from_string() {
     }
 }
 
+#[test]
+fn lakara_traditional_names() {
+    for val in Lakara::iter() {
+        assert_eq!(val, val.to_string().parse().expect("ok"));
+        assert_eq!(val, val.as_slp1().parse().expect("ok"));
+    }
+    assert_eq!(Lakara::AshirLin.as_slp1(), "ASIrliN");
+    assert_eq!("lfN".parse::<Lakara>().expect("ok"), Lakara::Lrn);
+    assert!("liN".parse::<Lakara>().is_err());
+}
+
 fn assert_derive_dhatu(dhatu: Dhatu, expected: &[&str]) {
     let v = Vyakarana::new();
     let prakriyas = v.derive_dhatus(&dhatu);
@@ -152,6 +169,45 @@ fn derive_tinantas() {
     assert_has_results(prakriyas, &["kurute"]);
 }
 
+#[test]
+fn derive_tinantas_from_many_threads() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let v = Arc::new(Vyakarana::new());
+    let cases = [
+        ("BU", Gana::Bhvadi, "Bavati"),
+        ("qukf\\Y", Gana::Tanadi, "karoti"),
+        ("gA\\", Gana::Adadi, "gAti"),
+        ("ci\\Y", Gana::Svadi, "cinoti"),
+    ];
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let v = Arc::clone(&v);
+            let (u, gana, expected) = cases[i % cases.len()];
+            thread::spawn(move || {
+                for _ in 0..20 {
+                    let args = Tinanta::builder()
+                        .dhatu(mula(u, gana))
+                        .prayoga(Prayoga::Kartari)
+                        .purusha(Purusha::Prathama)
+                        .vacana(Vacana::Eka)
+                        .lakara(Lakara::Lat)
+                        .build()
+                        .unwrap();
+                    let prakriyas = v.derive_tinantas(&args);
+                    assert!(prakriyas.iter().any(|p| p.text() == expected));
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
 #[test]
 fn derive_tinantas_with_invalid_dhatu() {
     let v = Vyakarana::new();
@@ -186,6 +242,38 @@ fn derive_tinantas_with_invalid_dhatu() {
     assert_has_results(prakriyas, &[]);
 }
 
+#[test]
+fn derive_all_lakaras() {
+    let v = Vyakarana::new();
+    let bhu = mula("BU", Gana::Bhvadi);
+    let tables = v.derive_all_lakaras(&bhu, Prayoga::Kartari);
+
+    use Lakara::*;
+    let keys: Vec<_> = tables.keys().copied().collect();
+    assert_eq!(
+        keys,
+        vec![Lat, Lit, Lut, Lrt, Lot, Lan, VidhiLin, AshirLin, Lun, Lrn]
+    );
+    assert_eq!(
+        tables[&Lat].texts(Purusha::Prathama, Vacana::Eka),
+        vec!["Bavati"]
+    );
+    assert_eq!(
+        tables[&Lit].texts(Purusha::Prathama, Vacana::Bahu),
+        vec!["baBUvuH"]
+    );
+    assert_eq!(
+        tables[&Lun].texts(Purusha::Uttama, Vacana::Eka),
+        vec!["aBUvam"]
+    );
+
+    // leT is Vedic.
+    let chandasi = Vyakarana::builder().is_chandasi(true).build();
+    assert!(chandasi
+        .derive_all_lakaras(&bhu, Prayoga::Kartari)
+        .contains_key(&Let));
+}
+
 #[test]
 fn derive_krdantas() {
     let v = Vyakarana::new();
@@ -210,3 +298,1442 @@ fn derive_krdantas() {
     let prakriyas = v.derive_krdantas(&krdanta);
     assert_has_results(prakriyas, &["kArayitvA"]);
 }
+
+#[test]
+fn derive_subantas_from_krdanta() {
+    let v = Vyakarana::new();
+
+    // dhatu -> krdanta
+    let kara = Krdanta::new(mula("qukf\\Y", Gana::Tanadi), Krt::GaY);
+    let prakriyas = v.derive_krdantas(&kara);
+    assert_has_results(prakriyas.clone(), &["kAra"]);
+
+    // Pin the krdanta to the result we just derived.
+    let kara = kara.with_require(prakriyas[0].text());
+
+    // krdanta -> subanta
+    let args = Subanta::new(kara.clone(), Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    assert_has_results(v.derive_subantas(&args), &["kAraH"]);
+
+    // krdanta -> taddhitanta -> subanta
+    let karavat = Taddhitanta::new(kara, Taddhita::matup);
+    assert_has_results(v.derive_taddhitantas(&karavat), &["kAravat"]);
+    let args = Subanta::new(karavat, Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    assert_has_results(v.derive_subantas(&args), &["kAravAn"]);
+}
+
+#[test]
+fn derive_tinantas_has_stable_order() {
+    let gup = mula("gupU~", Gana::Bhvadi);
+    let args = Tinanta::builder()
+        .dhatu(gup)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lit)
+        .build()
+        .unwrap();
+
+    let texts =
+        |prakriyas: Vec<Prakriya>| -> Vec<String> { prakriyas.iter().map(|p| p.text()).collect() };
+
+    let v = Vyakarana::new();
+    let first = v.derive_tinantas(&args);
+    assert!(first.len() > 1);
+
+    // The first result accepts every optional rule.
+    assert!(first[0]
+        .rule_choices()
+        .iter()
+        .all(|c| c.decision() == Decision::Accept));
+
+    // Repeated calls (with the same or a new `Vyakarana`) return the same order.
+    let first = texts(first);
+    assert_eq!(first, texts(v.derive_tinantas(&args)));
+    assert_eq!(first, texts(Vyakarana::new().derive_tinantas(&args)));
+}
+
+#[test]
+fn prakriya_rule_choices() {
+    // cakAra and cakara differ only in whether they use optional rule 7.1.91.
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let args = Tinanta::builder()
+        .dhatu(kr)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Uttama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lit)
+        .pada(DhatuPada::Parasmai)
+        .build()
+        .unwrap();
+
+    let v = Vyakarana::new();
+    let prakriyas = v.derive_tinantas(&args);
+    assert_has_results(prakriyas.clone(), &["cakAra", "cakara"]);
+
+    let find = |p: &Prakriya| {
+        p.rule_choices()
+            .iter()
+            .find(|c| c.rule() == Rule::Ashtadhyayi("7.1.91"))
+            .copied()
+            .expect("has choice")
+    };
+    let first = find(&prakriyas[0]);
+    let second = find(&prakriyas[1]);
+    assert_eq!(first.decision(), Decision::Accept);
+    assert_eq!(second.decision(), first.decision().opposite());
+}
+
+#[test]
+fn rule_parts() {
+    let rule = Rule::Ashtadhyayi("3.1.68");
+    assert_eq!(rule.source(), "ashtadhyayi");
+    assert_eq!(rule.ashtadhyayi_parts(), Some((3, 1, 68)));
+    assert_eq!(rule.unadipatha_parts(), None);
+
+    let rule = Rule::Unadipatha("2.105:1");
+    assert_eq!(rule.source(), "unadi");
+    assert_eq!(rule.ashtadhyayi_parts(), None);
+    assert_eq!(rule.unadipatha_parts(), Some((2, 105)));
+
+    assert_eq!(Rule::Varttika("1.1.33.1").ashtadhyayi_parts(), None);
+    assert_eq!(Rule::Phit("1.1").source(), "phit");
+
+    // Every Ashtadhyayi rule in a real derivation should have well-formed parts.
+    let v = Vyakarana::new();
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    for p in v.derive_tinantas(&args) {
+        for step in p.history() {
+            // Skip the blank entries that debug builds add to the history.
+            if let Rule::Ashtadhyayi(code) = step.rule() {
+                if code.trim().is_empty() {
+                    continue;
+                }
+                assert!(
+                    step.rule().ashtadhyayi_parts().is_some(),
+                    "{:?}",
+                    step.rule()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn derive_tinantas_with_preferred_only() {
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let args = Tinanta::builder()
+        .dhatu(kr)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Uttama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lit)
+        .pada(DhatuPada::Parasmai)
+        .build()
+        .unwrap();
+
+    let v = Vyakarana::new();
+    let all = v.derive_tinantas(&args);
+    assert_has_results(all.clone(), &["cakAra", "cakara"]);
+
+    let v = Vyakarana::builder()
+        .optional_mode(OptionalMode::PreferredOnly)
+        .build();
+    let preferred = v.derive_tinantas(&args);
+    assert_eq!(preferred.len(), 1);
+    assert_eq!(preferred[0].text(), all[0].text());
+}
+
+#[test]
+fn derive_tinantas_with_explain_failures() {
+    // BU is parasmaipadI, so it has no kartari Atmanepada forms.
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .pada(DhatuPada::Atmane)
+        .build()
+        .unwrap();
+
+    let v = Vyakarana::new();
+    assert!(v.derive_tinantas(&args).is_empty());
+
+    let v = Vyakarana::builder().explain_failures(true).build();
+    let failures = v.derive_tinantas(&args);
+    assert!(!failures.is_empty());
+    for p in &failures {
+        let reason = p.failure().expect("has failure");
+        assert!(!reason.is_empty());
+        assert!(!p.history().is_empty());
+    }
+
+    // Successful derivations have no failure, even in explain mode.
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .pada(DhatuPada::Parasmai)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_tinantas(&args);
+    assert_eq!(prakriyas.len(), 1);
+    assert_eq!(prakriyas[0].failure(), None);
+}
+
+#[test]
+fn derive_krdantas_with_antargana() {
+    let v = Vyakarana::new();
+
+    // kuw is kuwAdi, so its tfc-pratyaya is Nit (1.2.1) and blocks guna.
+    let kut = Dhatu::builder()
+        .aupadeshika("kuwa~")
+        .gana(Gana::Tudadi)
+        .antargana(Antargana::Kutadi)
+        .build()
+        .unwrap();
+    assert_eq!(kut.antargana(), Some(Antargana::Kutadi));
+    let prakriyas = v.derive_krdantas(&Krdanta::new(kut, Krt::tfc));
+    assert_has_results(prakriyas, &["kuwitf"]);
+
+    // The same root without an antargana receives guna.
+    let kut = mula("kuwa~", Gana::Tudadi);
+    assert_eq!(kut.antargana(), None);
+    let prakriyas = v.derive_krdantas(&Krdanta::new(kut, Krt::tfc));
+    assert_has_results(prakriyas, &["kowitf"]);
+}
+
+#[test]
+fn prakriya_text_with_boundaries() {
+    let v = Vyakarana::new();
+
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_tinantas(&args);
+    assert_eq!(prakriyas.len(), 1);
+    assert_eq!(prakriyas[0].text_with_boundaries("·"), "Bav·a·ti");
+
+    let args = Krdanta::new(mula("qukf\\Y", Gana::Tanadi), Krt::ktvA);
+    let prakriyas = v.derive_krdantas(&args);
+    assert_eq!(prakriyas.len(), 1);
+    assert_eq!(prakriyas[0].text_with_boundaries("·"), "kf·tvA");
+
+    // Terms emptied during the derivation are skipped.
+    let args = Krdanta::new(mula("SriY", Gana::Bhvadi), Krt::kvip);
+    let prakriyas = v.derive_krdantas(&args);
+    assert_eq!(prakriyas.len(), 1);
+    assert_eq!(prakriyas[0].text_with_boundaries("·"), "SrI");
+}
+
+#[test]
+fn prakriya_text_ipa() {
+    let v = Vyakarana::new();
+
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_tinantas(&args);
+    assert_eq!(prakriyas[0].text_ipa(), "bʱɐʋɐt̪i");
+
+    // Visarga is unvoiced [h].
+    let duhkha = Pratipadika::basic(Slp1String::from("duHKa").unwrap());
+    let args = Subanta::new(duhkha, Linga::Napumsaka, Vibhakti::Prathama, Vacana::Eka);
+    let prakriyas = v.derive_subantas(&args);
+    assert_eq!(prakriyas[0].text(), "duHKam");
+    assert_eq!(prakriyas[0].text_ipa(), "d̪uhkʰɐm");
+
+    // In contrast, h is voiced [ɦ].
+    let args = Subanta::new(
+        Pratipadika::basic(Slp1String::from("gfha").unwrap()),
+        Linga::Napumsaka,
+        Vibhakti::Prathama,
+        Vacana::Bahu,
+    );
+    let prakriyas = v.derive_subantas(&args);
+    assert_eq!(prakriyas[0].text_ipa(), "gr̩ɦaːɳi");
+}
+
+#[test]
+fn prakriya_is_avyaya() {
+    let v = Vyakarana::new();
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+
+    let is_avyaya = |prakriyas: Vec<Prakriya>| -> Vec<bool> {
+        assert!(!prakriyas.is_empty());
+        prakriyas.iter().map(|p| p.is_avyaya()).collect()
+    };
+
+    // krdantas by 1.1.39 and 1.1.40.
+    for krt in [Krt::tumun, Krt::ktvA] {
+        assert!(vidyut_prakriya::args::Krt::from(krt).is_avyaya());
+        let prakriyas = v.derive_krdantas(&Krdanta::new(kr.clone(), krt));
+        assert!(is_avyaya(prakriyas).iter().all(|x| *x), "{krt:?}");
+    }
+    // lyap is sthAnivat to ktvA.
+    let prakriyas = v.derive_krdantas(&Krdanta::new(kr.clone().with_prefixes(&["pra"]), Krt::ktvA));
+    assert!(is_avyaya(prakriyas).iter().all(|x| *x));
+
+    assert!(!vidyut_prakriya::args::Krt::from(Krt::tfc).is_avyaya());
+    let prakriyas = v.derive_krdantas(&Krdanta::new(kr.clone(), Krt::tfc));
+    assert!(is_avyaya(prakriyas).iter().all(|x| !*x));
+
+    // nipAtas by 1.1.37.
+    let ca = Pratipadika::avyaya("ca".try_into().expect("ok"));
+    let prakriyas = v.derive_subantas(&Subanta::avyaya(ca));
+    assert!(is_avyaya(prakriyas).iter().all(|x| *x));
+
+    let rama = Pratipadika::basic("rAma".try_into().expect("ok"));
+    let args = Subanta::new(rama, Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    let prakriyas = v.derive_subantas(&args);
+    assert!(is_avyaya(prakriyas).iter().all(|x| !*x));
+
+    // avyayIBAva samAsas by 1.1.41.
+    let upa = Pratipadika::basic("upa".try_into().expect("ok"));
+    let agni = Pratipadika::basic("agni".try_into().expect("ok"));
+    let args = Samasa::builder()
+        .padas(vec![
+            Subanta::avyaya(upa),
+            Subanta::new(agni, Linga::Pum, Vibhakti::Prathama, Vacana::Eka),
+        ])
+        .samasa_type(SamasaType::Avyayibhava)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_samasas(&args);
+    assert!(is_avyaya(prakriyas).iter().all(|x| *x));
+}
+
+#[test]
+fn prakriya_final_term_tags() {
+    let v = Vyakarana::new();
+    let bhu = mula("BU", Gana::Bhvadi);
+
+    let args = Tinanta::builder()
+        .dhatu(bhu.clone())
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_tinantas(&args);
+    let tags = prakriyas[0].final_term_tags();
+    assert!(tags.contains(&Samjna::Dhatu));
+    assert!(tags.contains(&Samjna::Tin));
+    assert!(!tags.contains(&Samjna::Krt));
+
+    let prakriyas = v.derive_krdantas(&Krdanta::new(bhu, Krt::kta));
+    let tags = prakriyas[0].final_term_tags();
+    assert!(tags.contains(&Samjna::Krt));
+    assert!(tags.contains(&Samjna::Nistha));
+    assert!(!tags.contains(&Samjna::Tin));
+
+    let sarva = Pratipadika::basic("sarva".try_into().expect("ok"));
+    let args = Subanta::new(sarva, Linga::Pum, Vibhakti::Prathama, Vacana::Bahu);
+    let prakriyas = v.derive_subantas(&args);
+    let tags = prakriyas[0].final_term_tags();
+    assert!(tags.contains(&Samjna::Sarvanama));
+    assert!(tags.contains(&Samjna::Sup));
+    assert!(!tags.contains(&Samjna::Tin));
+}
+
+#[test]
+fn derive_tinantas_with_sanadi_dhatu() {
+    let v = Vyakarana::new();
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let edh = mula("eDa~\\", Gana::Bhvadi);
+
+    let args = |dhatu: Dhatu, prayoga: Prayoga, pada: Option<DhatuPada>| {
+        let mut builder = Tinanta::builder()
+            .dhatu(dhatu)
+            .prayoga(prayoga)
+            .purusha(Purusha::Prathama)
+            .vacana(Vacana::Eka)
+            .lakara(Lakara::Lat);
+        if let Some(pada) = pada {
+            builder = builder.pada(pada);
+        }
+        builder.build().unwrap()
+    };
+
+    // Nic + karmani should use atmanepada by 1.3.13, even though the nijanta dhatu is ubhayapadI.
+    let kr_nic = kr.clone().with_sanadi(&[Sanadi::Ric]);
+    let prakriyas = v.derive_tinantas(&args(kr_nic.clone(), Prayoga::Karmani, None));
+    assert_has_results(prakriyas, &["kAryate"]);
+    let prakriyas = v.derive_tinantas(&args(
+        kr_nic.clone(),
+        Prayoga::Karmani,
+        Some(DhatuPada::Atmane),
+    ));
+    assert_has_results(prakriyas, &["kAryate"]);
+    let prakriyas = v.derive_tinantas(&args(kr_nic, Prayoga::Karmani, Some(DhatuPada::Parasmai)));
+    assert_has_results(prakriyas, &[]);
+
+    // Likewise for bhAve prayoga.
+    let bhu_nic = mula("BU", Gana::Bhvadi).with_sanadi(&[Sanadi::Ric]);
+    let prakriyas = v.derive_tinantas(&args(bhu_nic, Prayoga::Bhave, None));
+    assert_has_results(prakriyas, &["BAvyate"]);
+
+    // san follows the pada of the base dhatu (1.3.62).
+    let kr_san = kr.with_sanadi(&[Sanadi::san]);
+    let prakriyas = v.derive_tinantas(&args(kr_san.clone(), Prayoga::Kartari, None));
+    assert_has_results(prakriyas, &["cikIrzati", "cikIrzate"]);
+    let prakriyas = v.derive_tinantas(&args(kr_san, Prayoga::Karmani, None));
+    assert_has_results(prakriyas, &["cikIrzyate"]);
+
+    // A desiderative of an atmanepadI dhatu is also atmanepadI.
+    let edh_san = edh.with_sanadi(&[Sanadi::san]);
+    let prakriyas = v.derive_tinantas(&args(edh_san.clone(), Prayoga::Kartari, None));
+    assert_has_results(prakriyas, &["ediDizate"]);
+    let prakriyas = v.derive_tinantas(&args(edh_san, Prayoga::Kartari, Some(DhatuPada::Parasmai)));
+    assert_has_results(prakriyas, &[]);
+}
+
+#[test]
+fn derive_tinantas_with_san_it() {
+    let v = Vyakarana::new();
+
+    let san_dhatu = |u: &str, gana: Gana, san_it: Option<bool>| {
+        let mut args = SanadiArgs::new();
+        if let Some(value) = san_it {
+            args = args.with_san_it(value);
+        }
+        let dhatu = Dhatu::builder()
+            .aupadeshika(u)
+            .gana(gana)
+            .sanadi_with_args(&[Sanadi::san], args)
+            .build()
+            .unwrap();
+        assert_eq!(dhatu.sanadi_args().san_it(), san_it);
+        dhatu
+    };
+    let lat_tip = |dhatu: Dhatu| {
+        let args = Tinanta::builder()
+            .dhatu(dhatu)
+            .prayoga(Prayoga::Kartari)
+            .purusha(Purusha::Prathama)
+            .vacana(Vacana::Eka)
+            .lakara(Lakara::Lat)
+            .build()
+            .unwrap();
+        v.derive_tinantas(&args)
+    };
+
+    // div takes iw optionally (7.2.49).
+    let div = |san_it| san_dhatu("divu~", Divadi, san_it);
+    assert_has_results(lat_tip(div(None)), &["didevizati", "dudyUzati"]);
+    assert_has_results(lat_tip(div(Some(true))), &["didevizati"]);
+    assert_has_results(lat_tip(div(Some(false))), &["dudyUzati"]);
+
+    // paW is sew.
+    let path = |san_it| san_dhatu("paWa~", Bhvadi, san_it);
+    assert_has_results(lat_tip(path(Some(true))), &["pipaWizati"]);
+    assert_has_results(lat_tip(path(Some(false))), &[]);
+
+    // kf is aniw by 7.2.12.
+    let kr = |san_it| san_dhatu("qukf\\Y", Tanadi, san_it);
+    assert_has_results(lat_tip(kr(Some(false))), &["cikIrzati", "cikIrzate"]);
+    assert_has_results(lat_tip(kr(Some(true))), &[]);
+}
+
+#[test]
+fn available_krts() {
+    let v = Vyakarana::new();
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+
+    let krts = v.available_krts(&kr);
+    for krt in [Krt::tfc, Krt::ktvA, Krt::GaY, Krt::tumun, Krt::kta] {
+        assert!(krts.contains(&krt), "{krt:?}");
+    }
+    // Requires an upapada.
+    assert!(!krts.contains(&Krt::Kac));
+
+    // Cached results should be identical.
+    assert_eq!(krts, v.available_krts(&kr));
+}
+
+#[test]
+fn available_taddhitas() {
+    let v = Vyakarana::new();
+    let upagu = Pratipadika::basic("upagu".try_into().expect("ok"));
+
+    let taddhitas = v.available_taddhitas(&upagu);
+    assert!(taddhitas.contains(&Taddhita::aR));
+    assert!(taddhitas.contains(&Taddhita::tal));
+    assert_eq!(taddhitas, v.available_taddhitas(&upagu));
+}
+
+#[test]
+fn sandhi() {
+    let v = Vyakarana::new();
+
+    // vowel + vowel
+    assert_eq!(v.sandhi("na", "asti"), vec!["nAsti"]);
+    // consonant + consonant
+    assert_eq!(v.sandhi("tat", "ca"), vec!["tacca"]);
+    // visarga + consonant
+    assert_eq!(v.sandhi("rAmas", "gacCati"), vec!["rAmogacCati"]);
+    assert_eq!(v.sandhi("punar", "gacCati"), vec!["punargacCati"]);
+    // A final visarga could have come from either `s` or `r`.
+    assert_eq!(
+        v.sandhi("rAmaH", "gacCati"),
+        vec!["rAmogacCati", "rAmargacCati"]
+    );
+    assert_eq!(
+        v.sandhi("punaH", "gacCati"),
+        vec!["punogacCati", "punargacCati"]
+    );
+    // m + consonant, with optional parasavarna by 8.4.59.
+    assert_eq!(
+        v.sandhi("Palam", "KAdati"),
+        vec!["PalaMKAdati", "PalaNKAdati"]
+    );
+}
+
+#[test]
+fn sandhi_for_yan_ayadi_and_pragrhya() {
+    let v = Vyakarana::new();
+
+    // yaN (6.1.77)
+    assert_eq!(v.sandhi("maDu", "ari"), vec!["maDvari"]);
+    assert_eq!(v.sandhi("su", "Agatam"), vec!["svAgatam"]);
+    // ayAdi (6.1.78)
+    assert_eq!(v.sandhi("vizRo", "iha"), vec!["vizRaviha"]);
+    assert_eq!(v.sandhi("pO", "aka"), vec!["pAvaka"]);
+    // pUrvarUpa (6.1.109)
+    assert_eq!(v.sandhi("te", "atra"), vec!["tetra"]);
+    assert_eq!(v.sandhi("vizRo", "atra"), vec!["vizRotra"]);
+
+    // pragfhya blocks sandhi (1.1.11, 1.1.12)
+    let hari = Pratipadika::basic(Slp1String::from("hari").unwrap());
+    let hari_dvi = Subanta::new(hari, Linga::Pum, Vibhakti::Prathama, Vacana::Dvi);
+    assert_eq!(v.sandhi(hari_dvi, "etO"), vec!["harIetO"]);
+    assert_eq!(v.sandhi("amI", "aSvAH"), vec!["amIaSvAH"]);
+}
+
+#[test]
+fn derive_vakyas_with_derived_padas() {
+    let v = Vyakarana::new();
+    let vakya_texts = |padas: &[Pada]| -> Vec<String> {
+        let mut ret: Vec<_> = v
+            .derive_vakyas(padas)
+            .iter()
+            .map(|p| p.vakya_text())
+            .collect();
+        ret.dedup();
+        ret
+    };
+
+    let subanta = |stem: &str, linga, vibhakti, vacana| -> Pada {
+        let stem = Pratipadika::basic(Slp1String::from(stem).unwrap());
+        Subanta::new(stem, linga, vibhakti, vacana).into()
+    };
+    let rama = subanta("rAma", Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    let vanam = subanta("vana", Linga::Napumsaka, Vibhakti::Dvitiya, Vacana::Eka);
+    let gacchati: Pada = Tinanta::builder()
+        .dhatu(mula("ga\\mx~", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap()
+        .into();
+
+    assert_eq!(
+        vakya_texts(&[rama.clone(), vanam.clone(), gacchati.clone()]),
+        vec!["rAmo vanaM gacCati", "rAmo vanaN gacCati"]
+    );
+
+    // Sentence-final visarga
+    assert_eq!(
+        vakya_texts(&[vanam.clone(), gacchati, rama.clone()]),
+        vec!["vanaM gacCati rAmaH", "vanaN gacCati rAmaH"]
+    );
+
+    // Avagraha after 6.1.109
+    assert_eq!(vakya_texts(&[rama, "atra".into()]), vec!["rAmo 'tra"]);
+    assert_eq!(vakya_texts(&["te".into(), "atra".into()]), vec!["te 'tra"]);
+
+    // Vowel sandhi joins padas, but pragrhya padas stay separate.
+    assert_eq!(vakya_texts(&["na".into(), "asti".into()]), vec!["nAsti"]);
+    let hari = subanta("hari", Linga::Pum, Vibhakti::Prathama, Vacana::Dvi);
+    assert_eq!(vakya_texts(&[hari, "iti".into()]), vec!["harI iti"]);
+
+    // A visarga from `r` becomes `r` again, not `ru~`.
+    let dvar = subanta("dvAr", Linga::Stri, Vibhakti::Prathama, Vacana::Eka);
+    assert_eq!(
+        vakya_texts(&[dvar.clone(), "asti".into()]),
+        vec!["dvAr asti"]
+    );
+    assert_eq!(
+        vakya_texts(&[dvar.clone(), "gacCati".into()]),
+        vec!["dvAr gacCati"]
+    );
+    assert_eq!(vakya_texts(&[dvar, "karoti".into()]), vec!["dvAH karoti"]);
+    let pitar = subanta("pitf", Linga::Pum, Vibhakti::Sambodhana, Vacana::Eka);
+    assert_eq!(vakya_texts(&[pitar, "asti".into()]), vec!["pitar asti"]);
+
+    // Each variant of a pada makes its own vakya.
+    let shadhi: Pada = Tinanta::builder()
+        .dhatu(mula("SAsu~", Gana::Adadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Madhyama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lot)
+        .build()
+        .unwrap()
+        .into();
+    let mut actual: Vec<_> = v
+        .derive_vakyas(&[shadhi.clone(), "iti".into()])
+        .iter()
+        .map(|p| p.text())
+        .collect();
+    actual.sort();
+    actual.dedup();
+    assert_eq!(actual, vec!["SADIti", "SizwAditi"]);
+
+    // Padas are derived with the same options as the vakya.
+    let no_sha = Vyakarana::builder()
+        .disable_rules(&[Rule::from("6.4.35")])
+        .build();
+    let mut actual: Vec<_> = no_sha
+        .derive_vakyas(&[shadhi, "iti".into()])
+        .iter()
+        .map(|p| p.text())
+        .collect();
+    actual.dedup();
+    assert_eq!(actual, vec!["SizwAditi"]);
+}
+
+#[test]
+fn disable_rules() {
+    let v = Vyakarana::new();
+    let no_yan = Vyakarana::builder()
+        .disable_rules(&[Rule::from("6.1.77")])
+        .build();
+
+    // A derivation that needs a disabled rule fails.
+    assert_eq!(v.sandhi("maDu", "ari"), vec!["maDvari"]);
+    assert!(no_yan.sandhi("maDu", "ari").is_empty());
+
+    let nadi = Pratipadika::basic(Slp1String::from("nadI").unwrap());
+    let args = Subanta::new(nadi, Linga::Stri, Vibhakti::Prathama, Vacana::Bahu);
+    let texts = |v: &Vyakarana| -> Vec<String> {
+        v.derive_subantas(&args).iter().map(|p| p.text()).collect()
+    };
+    assert_eq!(texts(&v), vec!["nadyaH"]);
+    assert!(texts(&no_yan).is_empty());
+
+    // Derivations that don't need the rule are unaffected.
+    let rama = Pratipadika::basic(Slp1String::from("rAma").unwrap());
+    let args = Subanta::new(rama, Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    let prakriyas = no_yan.derive_subantas(&args);
+    assert_eq!(prakriyas[0].text(), "rAmaH");
+
+    // With `explain_failures`, the failure names the disabled rule.
+    let explain = Vyakarana::builder()
+        .disable_rules(&[Rule::from("6.1.77")])
+        .explain_failures(true)
+        .build();
+    let nadi = Pratipadika::basic(Slp1String::from("nadI").unwrap());
+    let args = Subanta::new(nadi, Linga::Stri, Vibhakti::Prathama, Vacana::Bahu);
+    let prakriyas = explain.derive_subantas(&args);
+    assert!(prakriyas
+        .iter()
+        .all(|p| p.failure() == Some("rule 6.1.77 is disabled")));
+
+    // A disabled optional rule is always declined. Here, 8.4.59 optionally blocks 8.4.58.
+    let no_parasavarna = Vyakarana::builder()
+        .disable_rules(&[Rule::from("8.4.59")])
+        .build();
+    assert_eq!(
+        no_parasavarna.sandhi("Palam", "KAdati"),
+        vec!["PalaNKAdati"]
+    );
+}
+
+#[test]
+fn rule_choices_for_vet_dhatu() {
+    // gAhU~ is udit, so 7.2.44 optionally blocks iw-Agama.
+    let gah = mula("gAhU~\\", Gana::Bhvadi);
+    let args = Tinanta::builder()
+        .dhatu(gah)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lrt)
+        .build()
+        .unwrap();
+    let texts = |v: &Vyakarana| -> Vec<String> {
+        v.derive_tinantas(&args).iter().map(|p| p.text()).collect()
+    };
+
+    let v = Vyakarana::new();
+    assert_has_results(v.derive_tinantas(&args), &["GAkzyate", "gAhizyate"]);
+
+    // Each result records which branch it took, and replaying that choice reproduces it.
+    for p in v.derive_tinantas(&args) {
+        let choice = p
+            .rule_choices()
+            .iter()
+            .find(|c| c.rule() == Rule::from("7.2.44"))
+            .copied()
+            .expect("has choice");
+        let replay = Vyakarana::builder().rule_choices(&[choice]).build();
+        assert_eq!(texts(&replay), vec![p.text()]);
+    }
+
+    let anit = Vyakarana::builder()
+        .rule_choices(&[RuleChoice::new("7.2.44", Decision::Accept)])
+        .build();
+    let set = Vyakarana::builder()
+        .rule_choices(&[RuleChoice::new("7.2.44", Decision::Decline)])
+        .build();
+    assert_eq!(texts(&anit), vec!["GAkzyate"]);
+    assert_eq!(texts(&set), vec!["gAhizyate"]);
+
+    // Other optional rules are still explored.
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let args = Tinanta::builder()
+        .dhatu(kr)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Uttama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lit)
+        .pada(DhatuPada::Parasmai)
+        .build()
+        .unwrap();
+    assert_has_results(set.derive_tinantas(&args), &["cakAra", "cakara"]);
+}
+
+#[test]
+fn enum_iterators_for_paradigms() {
+    use Vibhakti::*;
+
+    assert_eq!(
+        Vacana::iter().collect::<Vec<_>>(),
+        vec![Vacana::Eka, Vacana::Dvi, Vacana::Bahu]
+    );
+    assert_eq!(
+        Purusha::iter().collect::<Vec<_>>(),
+        vec![Purusha::Prathama, Purusha::Madhyama, Purusha::Uttama]
+    );
+    assert_eq!(
+        Linga::iter().collect::<Vec<_>>(),
+        vec![Linga::Pum, Linga::Stri, Linga::Napumsaka]
+    );
+    assert_eq!(
+        Vibhakti::iter().collect::<Vec<_>>(),
+        vec![Prathama, Dvitiya, Trtiya, Caturthi, Panchami, Sasthi, Saptami, Sambodhana]
+    );
+}
+
+#[test]
+fn citation_form() {
+    let v = Vyakarana::new();
+    let citation = |prati: Pratipadika, linga| -> Vec<String> {
+        v.citation_form(prati, linga)
+            .iter()
+            .map(|p| p.text())
+            .collect()
+    };
+    let basic = |text: &str| Pratipadika::basic(Slp1String::from(text).unwrap());
+    let nyap = |text: &str| Pratipadika::nyap(Slp1String::from(text).unwrap());
+
+    assert_eq!(citation(basic("deva"), Linga::Pum), vec!["devaH"]);
+    assert_eq!(citation(nyap("nadI"), Linga::Stri), vec!["nadI"]);
+    assert_eq!(citation(basic("Pala"), Linga::Napumsaka), vec!["Palam"]);
+
+    // Also works for derived stems.
+    let kartf = Krdanta::new(mula("qukf\\Y", Tanadi), Krt::tfc);
+    let prakriyas = v.citation_form(kartf, Linga::Pum);
+    assert_eq!(prakriyas[0].text(), "kartA");
+}
+
+#[test]
+fn dhatu_clean_text() {
+    let clean = |u, gana| mula(u, gana).clean_text().unwrap();
+    assert_eq!(clean("qukf\\Y", Tanadi), "kf");
+    assert_eq!(clean("RI\\Y", Bhvadi), "nI");
+    assert_eq!(clean("zWA\\", Bhvadi), "sTA");
+    assert_eq!(clean("ga\\mx~", Bhvadi), "gam");
+    assert_eq!(clean("vadi~\\", Bhvadi), "vand");
+
+    // Prefixes and sanadi are ignored.
+    let kr = mula("qukf\\Y", Tanadi)
+        .with_prefixes(&["pra"])
+        .with_sanadi(&[Sanadi::san]);
+    assert_eq!(kr.clean_text(), Some("kf".to_string()));
+
+    // Namadhatus have no clean text.
+    let putra = Pratipadika::basic(Slp1String::from("putra").unwrap());
+    assert_eq!(Dhatu::nama(putra, Some(Sanadi::kyac)).clean_text(), None);
+}
+
+#[cfg(feature = "lipi")]
+#[test]
+fn input_in_other_schemes() {
+    let v = Vyakarana::new();
+    let derive = |dhatu: Dhatu| -> Vec<String> {
+        let args = Tinanta::builder()
+            .dhatu(dhatu)
+            .prayoga(Prayoga::Kartari)
+            .lakara(Lakara::Lat)
+            .purusha(Purusha::Prathama)
+            .vacana(Vacana::Eka)
+            .build()
+            .unwrap();
+        v.derive_tinantas(&args).iter().map(|p| p.text()).collect()
+    };
+    let from_scheme = |text, scheme| Slp1String::from_scheme(text, scheme).unwrap();
+
+    let expected = derive(mula("BU", Bhvadi));
+    assert_eq!(expected, vec!["Bavati"]);
+    assert_eq!(
+        derive(Dhatu::mula(from_scheme("भू", Scheme::Devanagari), Bhvadi)),
+        expected
+    );
+    assert_eq!(
+        derive(Dhatu::mula(from_scheme("bhū", Scheme::Iast), Bhvadi)),
+        expected
+    );
+
+    // Prefixes and pratipadikas.
+    let sam = from_scheme("सम्", Scheme::Devanagari);
+    let gam = from_scheme("ga\\mx~", Scheme::Slp1);
+    assert_eq!(
+        derive(Dhatu::mula(gam, Bhvadi).with_prefixes(&[sam])),
+        derive(mula("ga\\mx~", Bhvadi).with_prefixes(&["sam"]))
+    );
+    assert_eq!(
+        Pratipadika::basic(from_scheme("देव", Scheme::Devanagari)),
+        Pratipadika::basic(Slp1String::from("deva").unwrap())
+    );
+
+    // Devanagari inherent `a`, anunasika, and accent.
+    assert_eq!(from_scheme("एधँ॒", Scheme::Devanagari).as_ref(), "eDa~\\");
+    assert_eq!(from_scheme("गमॢँ", Scheme::Devanagari).as_ref(), "gamx~");
+
+    // Anusvara variants.
+    assert_eq!(from_scheme("saṃskṛta", Scheme::Iast).as_ref(), "saMskfta");
+    assert_eq!(from_scheme("saṁskṛta", Scheme::Iast).as_ref(), "saMskfta");
+    assert_eq!(from_scheme("संस्कृत", Scheme::Devanagari).as_ref(), "saMskfta");
+
+    // Text that isn't valid SLP1 after transliteration is still an error.
+    assert!(Slp1String::from_scheme("भू १", Scheme::Devanagari).is_err());
+}
+
+#[test]
+fn prakriya_diff() {
+    let v = Vyakarana::new();
+    let args = Tinanta::builder()
+        .dhatu(mula("ga\\mx~", Bhvadi).with_prefixes(&["sam"]))
+        .prayoga(Prayoga::Kartari)
+        .lakara(Lakara::Lat)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .pada(DhatuPada::Parasmai)
+        .build()
+        .unwrap();
+    let prakriyas = v.derive_tinantas(&args);
+    let find = |text| prakriyas.iter().find(|p| p.text() == text).unwrap();
+    let anusvara = find("saMgacCati");
+    let parasavarna = find("saNgacCati");
+
+    // Both derivations share all steps until the optional 8.4.59.
+    let diff = parasavarna.diff(anusvara);
+    let first = &diff[0];
+    assert!(first.index() > 0);
+    assert_eq!(first.left().unwrap().rule(), Rule::from("8.4.58"));
+    assert_eq!(first.right().unwrap().rule(), Rule::from("8.4.59"));
+    assert_eq!(
+        parasavarna.history()[..first.index()],
+        anusvara.history()[..first.index()]
+    );
+
+    // Diffs are symmetric, and identical histories have no diff.
+    let reverse = anusvara.diff(parasavarna);
+    assert_eq!(reverse.len(), diff.len());
+    assert_eq!(reverse[0].left(), first.right());
+    assert!(anusvara.diff(anusvara).is_empty());
+
+    // A shorter history is padded with `None`.
+    let args = Krdanta::new(mula("BU", Bhvadi), Krt::tavya);
+    let mut pair = vec![anusvara.clone(), v.derive_krdantas(&args)[0].clone()];
+    pair.sort_by_key(|p| p.history().len());
+    let (short, long) = (&pair[0], &pair[1]);
+    assert!(short.history().len() < long.history().len());
+    let diff = short.diff(long);
+    assert!(diff.last().unwrap().left().is_none());
+    assert!(diff.last().unwrap().right().is_some());
+}
+
+#[test]
+fn tinanta_from_spec() {
+    let expected = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .lakara(Lakara::Lat)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .build()
+        .unwrap();
+    let actual = Tinanta::from_spec("BU|01.0001||kartari|law|prathama|eka").unwrap();
+    assert_eq!(actual, expected);
+
+    // With sanadi and surrounding whitespace.
+    let expected =
+        expected.with_dhatu(mula("BU", Gana::Bhvadi).with_sanadi(&[Sanadi::san, Sanadi::Ric]));
+    let actual = Tinanta::from_spec("BU| 01.0001 |san+Ric|kartari|lat|prathama|eka").unwrap();
+    assert_eq!(actual, expected);
+
+    // Invalid specs.
+    assert!(Tinanta::from_spec("BU|01.0001||kartari|law|prathama").is_err());
+    assert!(Tinanta::from_spec("BU|01.0001||kartari|liN|prathama|eka").is_err());
+    assert!(Tinanta::from_spec("BU|010001||kartari|law|prathama|eka").is_err());
+}
+
+#[test]
+fn krdanta_from_spec() {
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let expected = Krdanta::builder()
+        .dhatu(kr.clone())
+        .krt(Krt::kta)
+        .build()
+        .unwrap();
+    let actual = Krdanta::from_spec("qukf\\Y|08.0010||kta").unwrap();
+    assert_eq!(actual, expected);
+
+    let expected = Krdanta::builder()
+        .dhatu(kr.with_sanadi(&[Sanadi::san]))
+        .krt(Krt::tfc)
+        .build()
+        .unwrap();
+    let actual = Krdanta::from_spec("qukf\\Y|08.0010|san|tfc").unwrap();
+    assert_eq!(actual, expected);
+
+    // The antargana is inferred from the code.
+    let ghata = Krdanta::from_spec("Gawa~\\|01.0867||kta").unwrap();
+    assert!(ghata.dhatu().antargana().is_some());
+
+    assert!(Krdanta::from_spec("qukf\\Y|08.0010|kta").is_err());
+    assert!(Krdanta::from_spec("qukf\\Y|08.0010|foo|kta").is_err());
+}
+
+#[test]
+fn derive_subantas_for_any_pratipadika() {
+    let v = Vyakarana::new();
+    let prathama = |p: Pratipadika, linga| Subanta::new(p, linga, Vibhakti::Prathama, Vacana::Eka);
+    let basic = |s: &str| Pratipadika::basic(s.try_into().expect("ok"));
+
+    // Basic
+    let deva = prathama(basic("deva"), Linga::Pum);
+    assert_has_results(v.derive_subantas(&deva), &["devaH"]);
+
+    // nyAp
+    let rama = prathama(
+        Pratipadika::nyap("ramA".try_into().expect("ok")),
+        Linga::Stri,
+    );
+    assert_has_results(v.derive_subantas(&rama), &["ramA"]);
+
+    // Krdanta
+    let kartr = Krdanta::new(mula("qukf\\Y", Gana::Tanadi), Krt::tfc);
+    let kartr = prathama(kartr.into(), Linga::Pum);
+    assert_has_results(v.derive_subantas(&kartr), &["kartA"]);
+
+    // Taddhitanta
+    let karavat = Taddhitanta::new(basic("kara"), Taddhita::matup);
+    let karavat = prathama(karavat.into(), Linga::Pum);
+    assert_has_results(v.derive_subantas(&karavat), &["karavAn"]);
+
+    // Samasa
+    let rajapurusha = Samasa::builder()
+        .padas(vec![
+            Subanta::new(basic("rAjan"), Linga::Pum, Vibhakti::Sasthi, Vacana::Eka),
+            Subanta::new(basic("puruza"), Linga::Pum, Vibhakti::Prathama, Vacana::Eka),
+        ])
+        .samasa_type(SamasaType::Tatpurusha)
+        .build()
+        .unwrap();
+    let rajapurusha = prathama(rajapurusha.into(), Linga::Pum);
+    assert_has_results(v.derive_subantas(&rajapurusha), &["rAjapuruzaH"]);
+}
+
+#[test]
+fn prakriya_as_pratipadika_entry() {
+    let v = Vyakarana::new();
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let entry = |ps: Vec<Prakriya>| ps[0].as_pratipadika_entry().expect("ok");
+
+    let kfta = entry(v.derive_krdantas(&Krdanta::new(kr.clone(), Krt::kta)));
+    assert_eq!(kfta.text(), "kfta");
+    assert_eq!(kfta.antya_kind(), 'a');
+    assert_eq!(kfta.likely_linga(), None);
+
+    let kfti = entry(v.derive_krdantas(&Krdanta::new(kr.clone(), Krt::ktin)));
+    assert_eq!(kfti.text(), "kfti");
+    assert_eq!(kfti.antya_kind(), 'i');
+    assert_eq!(kfti.likely_linga(), Some(Linga::Stri));
+
+    let paka = mula("qupa\\ca~^z", Gana::Bhvadi);
+    let paka = entry(v.derive_krdantas(&Krdanta::new(paka, Krt::GaY)));
+    assert_eq!(paka.text(), "pAka");
+    assert_eq!(paka.likely_linga(), Some(Linga::Pum));
+
+    let kara = Pratipadika::basic("kara".try_into().expect("ok"));
+    let karavat = entry(v.derive_taddhitantas(&Taddhitanta::new(kara, Taddhita::matup)));
+    assert_eq!(karavat.text(), "karavat");
+    assert_eq!(karavat.antya_kind(), 't');
+
+    // Subantas are not pratipadikas.
+    let kfta = Krdanta::new(kr, Krt::kta);
+    let args = Subanta::new(kfta, Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    let prakriyas = v.derive_subantas(&args);
+    assert!(prakriyas[0].as_pratipadika_entry().is_none());
+}
+
+#[test]
+fn log_steps_levels() {
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let derive = |log_steps: LogSteps| {
+        let v = Vyakarana::builder().log_steps(log_steps).build();
+        v.derive_tinantas(&args).remove(0)
+    };
+
+    let p = derive(LogSteps::None);
+    assert_eq!(p.text(), "Bavati");
+    assert!(p.history().is_empty());
+
+    let p = derive(LogSteps::RulesOnly);
+    assert_eq!(p.text(), "Bavati");
+    assert!(p.history().iter().any(|s| s.rule().code() == "3.1.68"));
+    assert!(p.history().iter().all(|s| s.result().is_empty()));
+
+    let p = derive(LogSteps::Full);
+    assert_eq!(p.text(), "Bavati");
+    assert!(p.history().iter().any(|s| s.rule().code() == "3.1.68"));
+    assert!(p.history().iter().all(|s| !s.result().is_empty()));
+
+    // `bool` is still accepted.
+    let v = Vyakarana::builder().log_steps(false).build();
+    assert!(v.derive_tinantas(&args)[0].history().is_empty());
+}
+
+#[test]
+fn step_indices() {
+    let args = Tinanta::builder()
+        .dhatu(mula("BU", Gana::Bhvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let derive = |log_steps: LogSteps| {
+        let v = Vyakarana::builder().log_steps(log_steps).build();
+        v.derive_tinantas(&args).remove(0)
+    };
+
+    let full = derive(LogSteps::Full);
+    assert_eq!(full.steps().len(), full.step_count());
+    assert_eq!(full.steps(), full.history());
+
+    let last = full.get_step(full.step_count() - 1).unwrap();
+    let text: String = last.result().iter().map(|t| t.text()).collect();
+    assert_eq!(text, full.text());
+    assert!(full.get_step(full.step_count()).is_none());
+
+    // Indices are the same across logging levels.
+    let rules_only = derive(LogSteps::RulesOnly);
+    assert_eq!(rules_only.step_count(), full.step_count());
+    for i in 0..full.step_count() {
+        assert_eq!(
+            rules_only.get_step(i).map(|s| s.rule()),
+            full.get_step(i).map(|s| s.rule())
+        );
+    }
+
+    let none = derive(LogSteps::None);
+    assert_eq!(none.step_count(), 0);
+    assert!(none.get_step(0).is_none());
+}
+
+#[test]
+fn formation_summary() {
+    let v = Vyakarana::new();
+    let summaries = |prakriyas: Vec<Prakriya>| -> Vec<String> {
+        prakriyas
+            .iter()
+            .filter_map(|p| p.formation_summary())
+            .collect()
+    };
+
+    // krdanta
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+    let kfta = Krdanta::new(kr.clone(), Krt::kta);
+    assert_eq!(summaries(v.derive_krdantas(&kfta)), vec!["kfta (kf + kta)"]);
+
+    // krdanta with upasarga and sanadi
+    let kr_nic = kr
+        .clone()
+        .with_prefixes(&["pra"])
+        .with_sanadi(&[Sanadi::Ric]);
+    let args = Krdanta::new(kr_nic, Krt::tavya);
+    assert_eq!(
+        summaries(v.derive_krdantas(&args)),
+        vec!["prakArayitavya (pra + kf + Ric + tavya)"]
+    );
+
+    // taddhitanta
+    let upagu = Pratipadika::basic("upagu".try_into().unwrap());
+    let args = Taddhitanta::new(upagu, Taddhita::aR);
+    assert_eq!(
+        summaries(v.derive_taddhitantas(&args)),
+        vec!["Opagava (upagu + aR)"]
+    );
+
+    // krdanta -> taddhitanta
+    let args = Taddhitanta::new(kfta, Taddhita::tva);
+    assert_eq!(
+        summaries(v.derive_taddhitantas(&args)),
+        vec!["kftatva (kf + kta + tva)"]
+    );
+
+    // No summary for plain tinantas.
+    let args = Tinanta::builder()
+        .dhatu(kr)
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    assert!(summaries(v.derive_tinantas(&args)).is_empty());
+}
+
+#[test]
+fn tinanta_builder_with_karman() {
+    let v = Vyakarana::new();
+    let ni = mula("RI\\Y", Gana::Bhvadi);
+    let builder = || Tinanta::builder().dhatu(ni.clone()).lakara(Lakara::Lat);
+
+    // ahaM nIye
+    let args = builder()
+        .karman(Purusha::Uttama, Vacana::Eka)
+        .build()
+        .unwrap();
+    assert_eq!(args.prayoga(), Prayoga::Karmani);
+    assert_has_results(v.derive_tinantas(&args), &["nIye"]);
+
+    // te nIyante
+    let args = builder()
+        .karman(Purusha::Prathama, Vacana::Bahu)
+        .build()
+        .unwrap();
+    assert_has_results(v.derive_tinantas(&args), &["nIyante"]);
+
+    // The purusha defaults to prathama in karmani and bhave prayoga.
+    let args = builder()
+        .prayoga(Prayoga::Karmani)
+        .vacana(Vacana::Dvi)
+        .build()
+        .unwrap();
+    assert_eq!(args.purusha(), Purusha::Prathama);
+    assert_has_results(v.derive_tinantas(&args), &["nIyete"]);
+
+    let args = builder().prayoga(Prayoga::Bhave).build().unwrap();
+    assert_eq!(args.purusha(), Purusha::Prathama);
+    assert_eq!(args.vacana(), Vacana::Eka);
+
+    // But not in kartari prayoga.
+    assert!(builder()
+        .prayoga(Prayoga::Kartari)
+        .vacana(Vacana::Eka)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn results_cache() {
+    let tinanta = |u: &str| {
+        Tinanta::builder()
+            .dhatu(mula(u, Gana::Bhvadi))
+            .prayoga(Prayoga::Kartari)
+            .purusha(Purusha::Prathama)
+            .vacana(Vacana::Eka)
+            .lakara(Lakara::Lat)
+            .build()
+            .unwrap()
+    };
+    let bhu = tinanta("BU");
+    let gam = tinanta("ga\\mx~");
+
+    let v = Vyakarana::builder().cache_capacity(1).build();
+    let first = v.derive_tinantas(&bhu);
+    assert_eq!(v.cache_stats().hits(), 0);
+    assert_eq!(v.cache_stats().misses(), 1);
+
+    // The second identical call is served from the cache.
+    let second = v.derive_tinantas(&bhu);
+    assert_eq!(v.cache_stats().hits(), 1);
+    assert_eq!(v.cache_stats().misses(), 1);
+    assert_eq!(first.len(), second.len());
+    for (x, y) in first.iter().zip(&second) {
+        assert_eq!(x.text(), y.text());
+        assert_eq!(x.history(), y.history());
+    }
+
+    // Exceeding the capacity evicts the least recently used entry.
+    assert_has_results(v.derive_tinantas(&gam), &["gacCati"]);
+    assert_eq!(v.cache_stats().misses(), 2);
+    assert_has_results(v.derive_tinantas(&bhu), &["Bavati"]);
+    assert_eq!(v.cache_stats().hits(), 1);
+    assert_eq!(v.cache_stats().misses(), 3);
+
+    // Different derive_* methods don't share entries.
+    let kr = Krdanta::new(mula("BU", Gana::Bhvadi), Krt::tavya);
+    assert_has_results(v.derive_krdantas(&kr), &["Bavitavya"]);
+    assert_eq!(v.cache_stats().misses(), 4);
+
+    // A huge capacity is an upper bound, not an allocation.
+    let v = Vyakarana::builder().cache_capacity(usize::MAX).build();
+    assert_has_results(v.derive_tinantas(&bhu), &["Bavati"]);
+    assert_has_results(v.derive_tinantas(&gam), &["gacCati"]);
+    assert_has_results(v.derive_tinantas(&bhu), &["Bavati"]);
+    assert_eq!(v.cache_stats().hits(), 1);
+    assert_eq!(v.cache_stats().misses(), 2);
+
+    // The cache is disabled by default.
+    let v = Vyakarana::new();
+    v.derive_tinantas(&bhu);
+    v.derive_tinantas(&bhu);
+    assert_eq!(v.cache_stats(), CacheStats::default());
+}
+
+#[test]
+fn krt_arthas() {
+    let v = Vyakarana::new();
+    let derive = |dhatu: &Dhatu, krt: Krt, artha: KrtArtha| -> Vec<Prakriya> {
+        let args = Krdanta::builder()
+            .dhatu(dhatu.clone())
+            .krt(krt)
+            .artha(artha)
+            .build()
+            .unwrap();
+        v.derive_krdantas(&args)
+    };
+
+    // Every artha listed for a krt is a valid `KrtArtha`.
+    for krt in Krt::iter() {
+        for artha in krt.arthas() {
+            assert!(KrtArtha::iter().any(|a| a == *artha));
+        }
+    }
+
+    let arthas = Krt::GaY.arthas();
+    assert!(arthas.len() > 1);
+    assert!(arthas.contains(&KrtArtha::Bhava));
+    assert!(arthas.contains(&KrtArtha::Samjna));
+
+    // Each artha yields its own derivation.
+    let pac = mula("qupa\\ca~^z", Gana::Bhvadi);
+    for artha in arthas {
+        assert_has_results(derive(&pac, Krt::GaY, *artha), &["pAka"]);
+    }
+    let avatf = mula("tF", Gana::Bhvadi).with_prefixes(&["ava"]);
+    assert_has_results(derive(&avatf, Krt::GaY, KrtArtha::Samjna), &["avatAra"]);
+    assert_has_results(derive(&avatf, Krt::GaY, KrtArtha::Bhava), &[]);
+
+    let han = mula("ha\\na~", Gana::Adadi);
+    assert_has_results(derive(&han, Krt::ap, KrtArtha::Murti), &["Gana"]);
+    assert_has_results(derive(&han, Krt::ap, KrtArtha::Desha), &[]);
+}
+
+#[test]
+fn dhatu_aupadeshika_is_preserved() {
+    let kr = Dhatu::builder()
+        .aupadeshika("qukf\\Y")
+        .gana(Gana::Tanadi)
+        .build()
+        .unwrap();
+    assert_eq!(kr.aupadeshika(), Some("qukf\\Y"));
+
+    let sam_cikirsha = kr.with_sanadi(&[Sanadi::san]).with_prefixes(&["sam"]);
+    assert_eq!(sam_cikirsha.aupadeshika(), Some("qukf\\Y"));
+    assert_eq!(sam_cikirsha.clean_text(), Some("kf".to_string()));
+    assert_eq!(sam_cikirsha.sanadi(), &[Sanadi::san]);
+    assert_eq!(sam_cikirsha.prefixes(), &["sam".to_string()]);
+}
+
+#[test]
+fn prakriya_pada_predicates() {
+    let v = Vyakarana::new();
+    let kr = mula("qukf\\Y", Gana::Tanadi);
+
+    let args = Tinanta::builder()
+        .dhatu(kr.clone())
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let p = &v.derive_tinantas(&args)[0];
+    assert!(p.is_tinanta());
+    assert!(!p.is_subanta());
+    assert!(!p.is_krdanta());
+
+    let kfta = Krdanta::new(kr.clone(), Krt::kta);
+    let p = &v.derive_krdantas(&kfta)[0];
+    assert!(p.is_krdanta());
+    assert!(!p.is_subanta());
+    assert!(!p.is_taddhitanta());
+
+    // The stri-pratyaya and sup are skipped.
+    let args = Subanta::new(kfta, Linga::Stri, Vibhakti::Prathama, Vacana::Eka);
+    let p = &v.derive_subantas(&args)[0];
+    assert_eq!(p.text(), "kftA");
+    assert!(p.is_subanta());
+    assert!(p.is_krdanta());
+    assert!(!p.is_tinanta());
+
+    let upagu = Pratipadika::basic("upagu".try_into().expect("ok"));
+    let aupagava = Taddhitanta::new(upagu, Taddhita::aR);
+    let p = &v.derive_taddhitantas(&aupagava)[0];
+    assert!(p.is_taddhitanta());
+    assert!(!p.is_krdanta());
+    assert!(!p.is_samasa());
+
+    let rama = Pratipadika::basic("rAma".try_into().expect("ok"));
+    let args = Subanta::new(rama.clone(), Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    let p = &v.derive_subantas(&args)[0];
+    assert!(p.is_subanta());
+    assert!(!p.is_avyaya());
+    assert!(!p.is_krdanta());
+    assert!(!p.is_taddhitanta());
+    assert!(!p.is_samasa());
+
+    let ca = Pratipadika::avyaya("ca".try_into().expect("ok"));
+    let p = &v.derive_subantas(&Subanta::avyaya(ca))[0];
+    assert!(p.is_subanta());
+    assert!(p.is_avyaya());
+
+    let raja = Pratipadika::basic("rAjan".try_into().expect("ok"));
+    let args = Samasa::builder()
+        .padas(vec![
+            Subanta::new(raja, Linga::Pum, Vibhakti::Sasthi, Vacana::Eka),
+            Subanta::new(rama, Linga::Pum, Vibhakti::Prathama, Vacana::Eka),
+        ])
+        .samasa_type(SamasaType::Tatpurusha)
+        .build()
+        .unwrap();
+    let p = &v.derive_samasas(&args)[0];
+    assert!(p.is_samasa());
+    assert!(!p.is_tinanta());
+}
+
+#[test]
+fn disable_rules_from_history_without_panicking() {
+    use std::collections::BTreeSet;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    type Derive = Box<dyn Fn(&Vyakarana) -> Vec<Prakriya>>;
+    let mut derivations: Vec<Derive> = Vec::new();
+
+    let dhatus = [
+        (mula("BU", Bhvadi), DhatuPada::Parasmai),
+        (mula("qukf\\Y", Tanadi), DhatuPada::Parasmai),
+        (mula("ga\\mx~", Bhvadi), DhatuPada::Parasmai),
+        (mula("i\\R", Adadi), DhatuPada::Parasmai),
+        (mula("SIN", Adadi), DhatuPada::Atmane),
+    ];
+    for (dhatu, pada) in &dhatus {
+        for lakara in [Lakara::Lat, Lakara::Lit, Lakara::Lun, Lakara::Lot] {
+            let args = Tinanta::builder()
+                .dhatu(dhatu.clone())
+                .prayoga(Prayoga::Kartari)
+                .purusha(Purusha::Prathama)
+                .vacana(Vacana::Eka)
+                .lakara(lakara)
+                .pada(*pada)
+                .build()
+                .unwrap();
+            derivations.push(Box::new(move |v| v.derive_tinantas(&args)));
+        }
+        let sat = match pada {
+            DhatuPada::Parasmai => Krt::Satf,
+            DhatuPada::Atmane => Krt::SAnac,
+        };
+        for krt in [Krt::kta, Krt::ktvA, Krt::tfc, sat] {
+            let args = Krdanta::new(dhatu.clone(), krt);
+            derivations.push(Box::new(move |v| v.derive_krdantas(&args)));
+        }
+    }
+    for (stem, linga) in [
+        ("rAma", Linga::Pum),
+        ("nadI", Linga::Stri),
+        ("manas", Linga::Napumsaka),
+        ("rAjan", Linga::Pum),
+    ] {
+        for vibhakti in [Vibhakti::Prathama, Vibhakti::Trtiya, Vibhakti::Saptami] {
+            let prati = Pratipadika::basic(stem.try_into().expect("ok"));
+            let args = Subanta::new(prati, linga, vibhakti, Vacana::Eka);
+            derivations.push(Box::new(move |v| v.derive_subantas(&args)));
+        }
+    }
+
+    let v = Vyakarana::new();
+    let mut panics = Vec::new();
+    for derive in &derivations {
+        let prakriyas = derive(&v);
+        assert!(!prakriyas.is_empty());
+        // Skip debug steps, which have no rule code.
+        let rules: BTreeSet<_> = prakriyas
+            .iter()
+            .flat_map(|p| p.history().iter().map(|s| s.rule()))
+            .filter(|r| !r.code().trim().is_empty())
+            .collect();
+
+        for rule in rules {
+            let ablated = Vyakarana::builder().disable_rules(&[rule]).build();
+            match catch_unwind(AssertUnwindSafe(|| derive(&ablated))) {
+                Ok(prakriyas) => {
+                    for p in prakriyas {
+                        assert!(!p.history().iter().any(|s| s.rule() == rule));
+                    }
+                }
+                Err(_) => panics.push((prakriyas[0].text(), rule.code())),
+            }
+        }
+    }
+    assert!(panics.is_empty(), "{panics:?}");
+}
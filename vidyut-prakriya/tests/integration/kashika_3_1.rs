@@ -8,6 +8,7 @@ use vidyut_prakriya::args::Linga::*;
 use vidyut_prakriya::args::Taddhita as T;
 use vidyut_prakriya::args::TaddhitaArtha as TA;
 use vidyut_prakriya::args::*;
+use vidyut_prakriya::{Rule, Vyakarana};
 
 lazy_static! {
     static ref S: Tester = Tester::with_svara_rules();
@@ -335,6 +336,31 @@ fn sutra_3_1_26() {
 fn sutra_3_1_27() {
     let kandu = d("kaRqUY", Kandvadi);
     assert_has_lat(&[], &kandu, &["kaRqUyati", "kaRqUyate"]);
+
+    let mantu = d("mantuY", Kandvadi);
+    assert_has_tip(&[], &mantu, Lat, &["mantUyati"]);
+    assert_has_ta(&[], &mantu, Lat, &["mantUyate"]);
+    assert_has_tip(&[], &d("lew", Kandvadi), Lat, &["lewyati"]);
+    assert_has_ta(&[], &d("lew", Kandvadi), Lat, &[]);
+
+    // Not in the Kashika's list, but see SK 2573.
+    assert_has_tip(&[], &d("mfga", Kandvadi), Lat, &["mfgyati"]);
+
+    // Roots outside of the list should not receive yak.
+    let args = Tinanta::builder()
+        .dhatu(d("BU", Kandvadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lat)
+        .build()
+        .unwrap();
+    for p in Vyakarana::new().derive_tinantas(&args) {
+        assert!(p
+            .history()
+            .iter()
+            .all(|step| step.rule() != Rule::from("3.1.27")));
+    }
 }
 
 #[test]
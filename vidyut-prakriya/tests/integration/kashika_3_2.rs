@@ -1044,7 +1044,6 @@ fn sutra_3_2_107() {
     assert_has_krdanta(&[], &d("pA\\", Bhvadi), Krt::kvasu, &["papivas"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_3_2_108() {
     let sad = d("za\\dx~", Bhvadi);
@@ -1439,6 +1438,27 @@ fn sutra_3_2_177() {
     assert_has_krdanta(&["grAva"], &d("zwu\\Y", Adadi), Krt::kvip, &["grAvastut"]);
 }
 
+#[test]
+fn sutra_3_2_178_v1() {
+    let vac = d("va\\ca~", Adadi);
+    let prach = d("pra\\Ca~", Tudadi);
+    assert_has_krdanta(&[], &vac, Krt::kvip, &["vAc"]);
+    assert_has_krdanta(&[], &prach, Krt::kvip, &["prAS"]);
+    assert_has_upapada_krdanta("Ayata", &[], &d("zwu\\Y", Adadi), Krt::kvip, &["AyatastU"]);
+    assert_has_upapada_krdanta("kawa", &[], &d("pru\\N", Bhvadi), Krt::kvip, &["kawaprU"]);
+    assert_has_krdanta(&[], &d("SriY", Bhvadi), Krt::kvip, &["SrI"]);
+
+    let vac_kvip = krdanta(&[], &vac, Krt::kvip);
+    assert_has_sup_1s(&vac_kvip, Stri, &["vAk"]);
+    assert_has_sup_1d(&vac_kvip, Stri, &["vAcO"]);
+    assert_has_sup_3p(&vac_kvip, Stri, &["vAgBiH"]);
+
+    let prach_kvip = krdanta(&[], &prach, Krt::kvip);
+    assert_has_sup_1s(&prach_kvip, Pum, &["prAw"]);
+    assert_has_sup_1d(&prach_kvip, Pum, &["prASO"]);
+    assert_has_sup_3p(&prach_kvip, Pum, &["prAqBiH"]);
+}
+
 #[test]
 fn sutra_3_2_180() {
     let bhu = d("BU", Bhvadi);
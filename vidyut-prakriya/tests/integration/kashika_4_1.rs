@@ -506,6 +506,18 @@ fn sutra_4_1_99() {
     // TODO: others
 }
 
+#[test]
+fn sutra_4_1_101() {
+    let gargya = taddhitanta("garga", T::yaY);
+    let dakshi = taddhitanta("dakza", T::iY);
+    assert_has_artha_taddhita(&gargya, Yuvan, T::Pak, &["gArgyAyaRa"]);
+    assert_has_artha_taddhita(&dakshi, Yuvan, T::Pak, &["dAkzAyaRa"]);
+    // Without an explicit artha
+    assert_has_taddhita(&gargya, T::Pak, &["gArgyAyaRa"]);
+    // Blocks iY by 4.1.95.
+    assert_has_taddhita(&gargya, T::iY, &[]);
+}
+
 #[test]
 fn sutra_4_1_102() {
     assert_has_artha_taddhita("Saradvat", Gotra, T::Pak, &["SAradvatAyana"]);
@@ -659,21 +671,24 @@ fn sutra_4_1_124() {
     assert_has_artha_taddhita("kuzItaka", TasyaApatyam, T::iY, &["kOzItaki"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_4_1_125() {
     assert_has_artha_taddhita("BrU", TasyaApatyam, T::Qak, &["BrOveya"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_4_1_126() {
-    assert_has_artha_taddhita("kalyARI", TasyaApatyam, T::Qak, &["kalyARineya"]);
+    assert_has_artha_taddhita("kalyARI", TasyaApatyam, T::Qak, &["kAlyARineya"]);
+}
+
+#[ignore]
+#[test]
+fn sutra_4_1_126_ubhayapada_vrddhi() {
+    // TODO: these need 7.3.19.
     assert_has_artha_taddhita("suBagA", TasyaApatyam, T::Qak, &["sOBAgineya"]);
     assert_has_artha_taddhita("durBagA", TasyaApatyam, T::Qak, &["dOrBAgineya"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_4_1_127() {
     assert_has_taddhita(&nyap("kulawA"), T::Qak, &["kOlawineya", "kOlaweya"]);
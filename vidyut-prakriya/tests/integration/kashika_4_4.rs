@@ -724,3 +724,21 @@ fn sutra_4_4_118() {
     t.assert_has_artha_taddhita("samudra", TatraBhava, T::Ga, &["samudriya"]);
     t.assert_has_artha_taddhita("aBra", TatraBhava, T::Ga, &["aBriya"]);
 }
+
+#[test]
+fn sutra_4_4_142() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_taddhita("sarva", T::tAtil, &["sarvatAti"]);
+    t.assert_has_taddhita("deva", T::tAtil, &["devatAti"]);
+
+    // Only in chandas.
+    assert_has_taddhita("sarva", T::tAtil, &[]);
+}
+
+#[test]
+fn sutra_4_4_143() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_taddhita("Siva", T::tAtil, &["SivatAti"]);
+    t.assert_has_taddhita("Sam", T::tAtil, &["SantAti"]);
+    t.assert_has_taddhita("arizwa", T::tAtil, &["arizwatAti"]);
+}
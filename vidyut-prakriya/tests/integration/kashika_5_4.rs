@@ -183,6 +183,14 @@ fn sutra_5_4_55() {
     assert_has_artha_taddhita("brAhmaRa", AbhutaTadbhava, T::trA, &["brAhmaRatrA"]);
 }
 
+#[test]
+fn sutra_5_4_57() {
+    assert_has_taddhita("pawat", T::qAc, &["pawapawA"]);
+    assert_has_taddhita("Dagat", T::qAc, &["DagaDagA"]);
+    // dvyajavarArdhAt?
+    assert_has_taddhita("Srat", T::qAc, &[]);
+}
+
 #[test]
 fn sutra_5_4_78() {
     assert_has_sasthi_tatpurusha("brahman", "varcas", &["brahmavarcasa"]);
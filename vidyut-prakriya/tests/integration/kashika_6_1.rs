@@ -903,6 +903,11 @@ fn sutra_6_1_97() {
     assert_has_sandhi("yUpa", "agram", &["yUpAgram"]);
 }
 
+#[test]
+fn sutra_6_1_100() {
+    assert_has_taddhita("pawat", T::qAc, &["pawapawA"]);
+}
+
 #[test]
 fn sutra_6_1_101() {
     assert_has_sandhi("daRqa", "agram", &["daRqAgram"]);
@@ -991,7 +996,6 @@ fn sutra_6_1_108() {
     // TODO: others
 }
 
-#[ignore]
 #[test]
 fn sutra_6_1_109() {
     assert_has_sandhi("agne", "atra", &["agne tra"]);
@@ -1003,11 +1007,15 @@ fn sutra_6_1_109() {
     assert_has_krdanta(&[], &d("ci\\Y", Svadi), Krt::lyuw, &["cayana"]);
     assert_has_krdanta(&[], &d("lUY", Kryadi), Krt::lyuw, &["lavana"]);
 
-    // ati
+    // TODO: others
+}
+
+#[ignore]
+#[test]
+fn sutra_6_1_109_ati() {
+    // TODO: these need `vAyo` and `BAno` as sambuddhi for 1.1.16.
     assert_has_sandhi("vAyo", "iti", &["vAyo iti"]);
     assert_has_sandhi("BAno", "iti", &["BAno iti"]);
-
-    // TODO: others
 }
 
 #[test]
@@ -1135,6 +1143,12 @@ fn sutra_6_1_141() {
 #[test]
 fn sutra_6_1_159() {
     S.assert_has_krdanta(&[], &d("kf\\za~", Bhvadi), Krt::GaY, &["karza/"]);
+    S.assert_has_krdanta(&[], &d("qupa\\ca~^z", Bhvadi), Krt::GaY, &["pAka/"]);
+    S.assert_has_krdanta(&[], &d("tya\\ja~", Bhvadi), Krt::GaY, &["tyAga/"]);
+    S.assert_has_krdanta(&[], &d("BU", Bhvadi), Krt::GaY, &["BAva/"]);
+
+    // Not ākāravat, so 6.1.197 applies instead.
+    S.assert_has_krdanta(&[], &d("Bu\\ja~", Rudhadi), Krt::GaY, &["Bo/ga"]);
 }
 
 #[ignore]
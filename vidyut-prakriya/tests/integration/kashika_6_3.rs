@@ -189,6 +189,51 @@ fn sutra_6_3_91() {
     assert_has_taddhita("yad", T::vatup, &["yAvat"]);
 }
 
+#[test]
+fn sutra_6_3_92() {
+    let anc = d("ancu~", Bhvadi);
+    let vishvadryac = create_upapada_krdanta("viSvadryac", "viSva", &[], &anc, Krt::kvin);
+    assert_has_sup_1s(&vishvadryac, Pum, &["viSvadryaN"]);
+    assert_has_sup_1d(&vishvadryac, Pum, &["viSvadryaYcO"]);
+    assert_has_sup_2p(&vishvadryac, Pum, &["viSvadrIcaH"]);
+
+    let devadryac = create_upapada_krdanta("devadryac", "deva", &[], &anc, Krt::kvin);
+    assert_has_sup_1s(&devadryac, Pum, &["devadryaN"]);
+    assert_has_sup_1d(&devadryac, Pum, &["devadryaYcO"]);
+    assert_has_sup_2p(&devadryac, Pum, &["devadrIcaH"]);
+}
+
+#[test]
+fn sutra_6_3_93() {
+    let samyac = create_krdanta("samyac", &["sam"], &d("ancu~", Bhvadi), Krt::kvin);
+    assert_has_sup_1s(&samyac, Pum, &["samyaN"]);
+    assert_has_sup_1d(&samyac, Pum, &["samyaYcO"]);
+    assert_has_sup_1p(&samyac, Pum, &["samyaYcaH"]);
+    assert_has_sup_2p(&samyac, Pum, &["samIcaH"]);
+}
+
+#[test]
+fn sutra_6_3_94() {
+    let anc = d("ancu~", Bhvadi);
+    assert_has_krdanta(&["tiras"], &anc, Krt::kvin, &["tiryac"]);
+
+    let tiryac = krdanta(&["tiras"], &anc, Krt::kvin);
+    assert_has_sup_1s(&tiryac, Pum, &["tiryaN"]);
+    assert_has_sup_1d(&tiryac, Pum, &["tiryaYcO"]);
+    assert_has_sup_1p(&tiryac, Pum, &["tiryaYcaH"]);
+    // alope?
+    assert_has_sup_2p(&tiryac, Pum, &["tiraScaH"]);
+    assert_has_sup_3s(&tiryac, Pum, &["tiraScA"]);
+}
+
+#[test]
+fn sutra_6_3_95() {
+    let sadhryac = create_upapada_krdanta("saDryac", "saha", &[], &d("ancu~", Bhvadi), Krt::kvin);
+    assert_has_sup_1s(&sadhryac, Pum, &["saDryaN"]);
+    assert_has_sup_1d(&sadhryac, Pum, &["saDryaYcO"]);
+    assert_has_sup_2p(&sadhryac, Pum, &["saDrIcaH"]);
+}
+
 #[test]
 fn sutra_6_3_101() {
     assert_has_avyaya_tatpurusha("ku", "aja", &["kadaja"]);
@@ -307,4 +352,9 @@ fn sutra_6_3_138() {
     assert_has_sup_2p(&madhvac, Pum, &["maDUcaH"]);
     assert_has_sup_3s(&madhvac, Pum, &["maDUcA"]);
     assert_has_sup_4s(&madhvac, Pum, &["maDUce"]);
+
+    let pratyac = create_krdanta("pratyac", &["prati"], &d("ancu~", Bhvadi), Krt::kvin);
+    assert_has_sup_2p(&pratyac, Pum, &["pratIcaH"]);
+    assert_has_sup_3s(&pratyac, Pum, &["pratIcA"]);
+    assert_has_sup_4s(&pratyac, Pum, &["pratIce"]);
 }
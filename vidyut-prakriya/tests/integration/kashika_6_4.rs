@@ -268,7 +268,6 @@ fn sutra_6_4_21() {
     assert_has_krdanta(&[], &dhurv, Krt::ktin, &["DUrti"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_6_4_22() {
     assert_has_sip(&[], &d("asa~", Adadi), Lot, &["eDi", "stAt"]);
@@ -1934,7 +1933,6 @@ fn sutra_6_4_130() {
     assert_has_taddhita(&vyaghrapad, T::yak, &["vEyAGrapadya"]);
 }
 
-#[ignore]
 #[test]
 fn sutra_6_4_131() {
     let vidvas = krdanta(&[], &d("vida~", Adadi), Krt::Satf);
@@ -2050,6 +2048,14 @@ fn sutra_6_4_138() {
     assert_has_sup_4s(&madhvac, Pum, &["maDUce"]);
 }
 
+#[test]
+fn sutra_6_4_139() {
+    let udac = create_krdanta("udac", &["ud"], &d("ancu~", Bhvadi), Krt::kvin);
+    assert_has_sup_2p(&udac, Pum, &["udIcaH"]);
+    assert_has_sup_3s(&udac, Pum, &["udIcA"]);
+    assert_has_sup_4s(&udac, Pum, &["udIce"]);
+}
+
 #[test]
 fn sutra_6_4_140() {
     let kilalapa = create_upapada_krdanta("kilAlapA", "kilAla", &[], &d("pA\\", Adadi), Krt::vic);
@@ -389,6 +389,38 @@ fn sutra_7_1_37() {
 
 // 7.1.38 - 7.1.50 are chAndasa.
 
+#[test]
+fn sutra_7_1_44() {
+    let t = Tester::with_chaandasa();
+    let su = d("zu\\Y", Svadi);
+    t.assert_has_tha(&[], &su, Lot, &["sunuta", "sunutAt", "sunutana"]);
+}
+
+#[test]
+fn sutra_7_1_45() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_tha(&[], &d("quDA\\Y", Juhotyadi), Lan, &["aDatta", "aDattana"]);
+    t.assert_has_tha(&[], &d("ga\\mx~", Bhvadi), Lat, &["gacCaTa"]);
+    t.assert_has_tha(
+        &[],
+        &d("ga\\mx~", Bhvadi),
+        Lot,
+        &["gacCata", "gacCatAt", "gacCatana"],
+    );
+
+    // Not in classical derivations.
+    assert_has_tha(&[], &d("ga\\mx~", Bhvadi), Lot, &["gacCata"]);
+    assert_has_tha(&[], &d("zu\\Y", Svadi), Lot, &["sunuta"]);
+}
+
+#[test]
+fn sutra_7_1_46() {
+    let t = Tester::with_chaandasa();
+    let i = d("i\\R", Adadi);
+    t.assert_has_mas(&[], &i, Lat, &["imaH", "imasi"]);
+    assert_has_mas(&[], &i, Lat, &["imaH"]);
+}
+
 #[test]
 fn sutra_7_1_52() {
     assert_has_sup_6p("sarva", Pum, &["sarvezAm"]);
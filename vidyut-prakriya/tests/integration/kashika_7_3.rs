@@ -231,6 +231,14 @@ fn sutra_7_3_43() {
     );
 }
 
+#[test]
+fn sutra_7_3_44() {
+    let kr = d("qukf\\Y", Tanadi);
+    let hr = d("hf\\Y", Bhvadi);
+    assert_has_sup_1s(krdanta(&[], &kr, Krt::Rvul), Stri, &["kArikA"]);
+    assert_has_sup_1s(krdanta(&[], &hr, Krt::Rvul), Stri, &["hArikA"]);
+}
+
 #[test]
 fn sutra_7_3_50() {
     assert_has_taddhita("akza", T::Wak, &["Akzika"]);
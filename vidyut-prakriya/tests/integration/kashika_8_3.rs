@@ -131,6 +131,16 @@ fn sutra_8_3_24() {
     assert_has_ta_k(&[], &d("ga\\mx~", Bhvadi), Lat, &["gamyate"]);
 }
 
+#[test]
+fn sutra_8_3_28() {
+    assert_has_sandhi("prAN", "Sete", &["prANk Sete", "prANk Cete", "prAN Sete"]);
+    assert_has_sandhi("prAN", "zazWaH", &["prANk zazWaH", "prAN zazWaH"]);
+    assert_has_sandhi("prAN", "sAyaH", &["prANk sAyaH", "prAN sAyaH"]);
+    assert_has_sandhi("sugaR", "Sete", &["sugaRw Sete", "sugaRw Cete", "sugaR Sete"]);
+    assert_has_sandhi("sugaR", "zazWaH", &["sugaRw zazWaH", "sugaR zazWaH"]);
+    assert_has_sandhi("sugaR", "sAyaH", &["sugaRw sAyaH", "sugaR sAyaH"]);
+}
+
 #[test]
 fn sutra_8_3_29() {
     assert_has_sandhi("Svaliw", "sAye", &["Svaliw sAye", "Svaliw tsAye"]);
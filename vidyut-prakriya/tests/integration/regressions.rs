@@ -5,11 +5,13 @@
 extern crate test_utils;
 use test_utils::*;
 use vidyut_prakriya::args::Gana::*;
-use vidyut_prakriya::args::Krdanta;
 use vidyut_prakriya::args::Lakara::*;
 use vidyut_prakriya::args::Linga::*;
-use vidyut_prakriya::args::{BaseKrt as Krt, Dhatu, Lakara, Prayoga};
-use vidyut_prakriya::Vyakarana;
+use vidyut_prakriya::args::{
+    BaseKrt as Krt, Dhatu, Lakara, Prayoga, Purusha, Tinanta, Vacana, Vibhakti,
+};
+use vidyut_prakriya::args::{Krdanta, Pada, Pratipadika, Subanta, Taddhitanta};
+use vidyut_prakriya::{Rule, Vyakarana};
 
 #[test]
 fn ambibat() {
@@ -260,3 +262,1746 @@ fn kr_sat() {
         &["karizyamARa", "kArizyamARa"],
     );
 }
+
+// Lit forms for vowel-final roots, including the iw-Agama before Ta and se.
+#[test]
+fn lit_of_vowel_final_dhatus() {
+    let nii = d("RI\\Y", Bhvadi);
+    assert_has_tip(&[], &nii, Lit, &["ninAya"]);
+    assert_has_tas(&[], &nii, Lit, &["ninyatuH"]);
+    assert_has_jhi(&[], &nii, Lit, &["ninyuH"]);
+    assert_has_sip(&[], &nii, Lit, &["ninayiTa", "nineTa"]);
+    assert_has_thas(&[], &nii, Lit, &["ninyaTuH"]);
+    assert_has_tha(&[], &nii, Lit, &["ninya"]);
+    assert_has_mip(&[], &nii, Lit, &["ninAya", "ninaya"]);
+    assert_has_vas(&[], &nii, Lit, &["ninyiva"]);
+    assert_has_mas(&[], &nii, Lit, &["ninyima"]);
+    assert_has_ta(&[], &nii, Lit, &["ninye"]);
+    assert_has_aataam(&[], &nii, Lit, &["ninyAte"]);
+    assert_has_jha(&[], &nii, Lit, &["ninyire"]);
+    assert_has_thaas(&[], &nii, Lit, &["ninyize"]);
+    assert_has_aathaam(&[], &nii, Lit, &["ninyATe"]);
+    assert_has_dhvam(&[], &nii, Lit, &["ninyiDve", "ninyiQve"]);
+    assert_has_iw(&[], &nii, Lit, &["ninye"]);
+    assert_has_vahi(&[], &nii, Lit, &["ninyivahe"]);
+    assert_has_mahin(&[], &nii, Lit, &["ninyimahe"]);
+
+    let bhu = d("BU", Bhvadi);
+    assert_has_tip(&[], &bhu, Lit, &["baBUva"]);
+    assert_has_tas(&[], &bhu, Lit, &["baBUvatuH"]);
+    assert_has_jhi(&[], &bhu, Lit, &["baBUvuH"]);
+    assert_has_sip(&[], &bhu, Lit, &["baBUviTa"]);
+    assert_has_thas(&[], &bhu, Lit, &["baBUvaTuH"]);
+    assert_has_tha(&[], &bhu, Lit, &["baBUva"]);
+    assert_has_mip(&[], &bhu, Lit, &["baBUva"]);
+    assert_has_vas(&[], &bhu, Lit, &["baBUviva"]);
+    assert_has_mas(&[], &bhu, Lit, &["baBUvima"]);
+
+    let i = d("i\\R", Adadi);
+    assert_has_tip(&[], &i, Lit, &["iyAya"]);
+    assert_has_tas(&[], &i, Lit, &["IyatuH"]);
+    assert_has_jhi(&[], &i, Lit, &["IyuH"]);
+    assert_has_sip(&[], &i, Lit, &["iyayiTa", "iyeTa"]);
+    assert_has_thas(&[], &i, Lit, &["IyaTuH"]);
+    assert_has_tha(&[], &i, Lit, &["Iya"]);
+    assert_has_mip(&[], &i, Lit, &["iyAya", "iyaya"]);
+    assert_has_vas(&[], &i, Lit, &["Iyiva"]);
+    assert_has_mas(&[], &i, Lit, &["Iyima"]);
+
+    let kr = d("qukf\\Y", Tanadi);
+    assert_has_tip(&[], &kr, Lit, &["cakAra"]);
+    assert_has_tas(&[], &kr, Lit, &["cakratuH"]);
+    assert_has_jhi(&[], &kr, Lit, &["cakruH"]);
+    assert_has_sip(&[], &kr, Lit, &["cakarTa"]);
+    assert_has_thas(&[], &kr, Lit, &["cakraTuH"]);
+    assert_has_tha(&[], &kr, Lit, &["cakra"]);
+    assert_has_mip(&[], &kr, Lit, &["cakAra", "cakara"]);
+    assert_has_vas(&[], &kr, Lit, &["cakfva"]);
+    assert_has_mas(&[], &kr, Lit, &["cakfma"]);
+    assert_has_ta(&[], &kr, Lit, &["cakre"]);
+    assert_has_aataam(&[], &kr, Lit, &["cakrAte"]);
+    assert_has_jha(&[], &kr, Lit, &["cakrire"]);
+    assert_has_thaas(&[], &kr, Lit, &["cakfze"]);
+    assert_has_aathaam(&[], &kr, Lit, &["cakrATe"]);
+    assert_has_dhvam(&[], &kr, Lit, &["cakfQve"]);
+    assert_has_iw(&[], &kr, Lit, &["cakre"]);
+    assert_has_vahi(&[], &kr, Lit, &["cakfvahe"]);
+    assert_has_mahin(&[], &kr, Lit, &["cakfmahe"]);
+
+    // as --> BU by 2.4.52.
+    let as_ = d("asa~", Adadi);
+    assert_has_tip(&[], &as_, Lit, &["baBUva"]);
+    assert_has_tas(&[], &as_, Lit, &["baBUvatuH"]);
+    assert_has_jhi(&[], &as_, Lit, &["baBUvuH"]);
+    assert_has_sip(&[], &as_, Lit, &["baBUviTa"]);
+    assert_has_thas(&[], &as_, Lit, &["baBUvaTuH"]);
+    assert_has_tha(&[], &as_, Lit, &["baBUva"]);
+    assert_has_mip(&[], &as_, Lit, &["baBUva"]);
+    assert_has_vas(&[], &as_, Lit, &["baBUviva"]);
+    assert_has_mas(&[], &as_, Lit, &["baBUvima"]);
+}
+
+#[test]
+fn nishtha_with_na_adesha_and_it_agama() {
+    let assert_has_nishtha = |dhatu: &Dhatu, kta: &[&str], ktavatu: &[&str]| {
+        assert_has_krdanta(&[], dhatu, Krt::kta, kta);
+        assert_has_krdanta(&[], dhatu, Krt::ktavatu, ktavatu);
+    };
+
+    // na-Adesha by 8.2.42 -- 8.2.45.
+    assert_has_nishtha(&d("Bi\\di~^r", Rudhadi), &["Binna"], &["Binnavat"]);
+    assert_has_nishtha(&d("Ci\\di~^r", Rudhadi), &["Cinna"], &["Cinnavat"]);
+    assert_has_nishtha(&d("o~lasjI~\\", Tudadi), &["lagna"], &["lagnavat"]);
+    assert_has_nishtha(&d("lUY", Kryadi), &["lUna"], &["lUnavat"]);
+    assert_has_nishtha(
+        &d("divu~", Divadi),
+        &["dyUna", "dyUta"],
+        &["dyUnavat", "dyUtavat"],
+    );
+
+    // Loss of the penultimate nasal by 6.4.24.
+    assert_has_nishtha(&d("ba\\nDa~", Kryadi), &["badDa"], &["badDavat"]);
+    assert_has_nishtha(&d("Sansu~", Bhvadi), &["Sasta"], &["Sastavat"]);
+
+    // sew roots.
+    assert_has_nishtha(&d("liKa~", Tudadi), &["liKita"], &["liKitavat"]);
+    assert_has_nishtha(&d("paWa~", Bhvadi), &["paWita"], &["paWitavat"]);
+}
+
+#[test]
+fn krtya_pratyaya_selection() {
+    let assert_has_krtyas = |dhatu: &Dhatu, yat: &[&str], nyat: &[&str], kyap: &[&str]| {
+        assert_has_krdanta(&[], dhatu, Krt::yat, yat);
+        assert_has_krdanta(&[], dhatu, Krt::Ryat, nyat);
+        assert_has_krdanta(&[], dhatu, Krt::kyap, kyap);
+    };
+
+    // yat after a vowel (3.1.97) and after a labial with a short `a` (3.1.98).
+    assert_has_krtyas(&d("qudA\\Y", Juhotyadi), &["deya"], &[], &[]);
+    assert_has_krtyas(&d("ga\\mx~", Bhvadi), &["gamya"], &[], &[]);
+    // Ryat after a consonant (3.1.124). kutva by 7.3.52 is optionally blocked by 7.3.65.
+    assert_has_krtyas(&d("qupa\\ca~^z", Bhvadi), &[], &["pAkya", "pAcya"], &[]);
+    // kyap by 3.1.120. kftyA (strI) by 3.3.100.
+    assert_has_krtyas(&d("qukf\\Y", Tanadi), &[], &["kArya"], &["kftya", "kftyA"]);
+    // kyap and yat by 3.1.106 require an upapada.
+    let vad = d("vada~", Bhvadi);
+    assert_has_krtyas(&vad, &[], &["vAdya"], &[]);
+    assert_has_upapada_krdanta("brahma", &[], &vad, Krt::yat, &["brahmavadya"]);
+    assert_has_upapada_krdanta("brahma", &[], &vad, Krt::kyap, &["brahmodya"]);
+}
+
+#[test]
+fn consonant_final_stems() {
+    // jaS-tva (8.2.39), car-tva (8.4.55 - 8.4.56), and kutva (8.2.30).
+    assert_has_sup_1s("vAc", Stri, &["vAk"]);
+    assert_has_sup_1p("vAc", Stri, &["vAcaH"]);
+    assert_has_sup_3s("vAc", Stri, &["vAcA"]);
+    assert_has_sup_3p("vAc", Stri, &["vAgBiH"]);
+    assert_has_sup_7s("vAc", Stri, &["vAci"]);
+    assert_has_sup_7p("vAc", Stri, &["vAkzu"]);
+
+    // an-stems with upadhA-lopa (6.4.134) and optional lopa in the saptamI (6.4.136).
+    assert_has_sup_1s("rAjan", Pum, &["rAjA"]);
+    assert_has_sup_1p("rAjan", Pum, &["rAjAnaH"]);
+    assert_has_sup_3s("rAjan", Pum, &["rAjYA"]);
+    assert_has_sup_3p("rAjan", Pum, &["rAjaBiH"]);
+    assert_has_sup_7s("rAjan", Pum, &["rAjYi", "rAjani"]);
+    assert_has_sup_7p("rAjan", Pum, &["rAjasu"]);
+
+    // No upadhA-lopa after a conjunct ending in m (6.4.137).
+    assert_has_sup_1s("Atman", Pum, &["AtmA"]);
+    assert_has_sup_1p("Atman", Pum, &["AtmAnaH"]);
+    assert_has_sup_3s("Atman", Pum, &["AtmanA"]);
+    assert_has_sup_3p("Atman", Pum, &["AtmaBiH"]);
+    assert_has_sup_7s("Atman", Pum, &["Atmani"]);
+    assert_has_sup_7p("Atman", Pum, &["Atmasu"]);
+}
+
+#[test]
+fn nic_with_puk_and_mit_dhatus() {
+    // puk-Agama by 7.3.36.
+    assert_has_tip(&[], &nic(&d("jYA\\", Kryadi)), Lat, &["jYApayati"]);
+    assert_has_tip(&[], &nic(&d("gE\\", Bhvadi)), Lat, &["gApayati"]);
+    // Optional p by 7.3.43.
+    assert_has_tip(
+        &[],
+        &nic(&d("ru\\ha~", Bhvadi)),
+        Lat,
+        &["rohayati", "ropayati"],
+    );
+
+    // mit dhatus shorten their vowel by 6.4.92.
+    assert_has_tip(&[], &nic(&d_ghatadi("jYA", Bhvadi)), Lat, &["jYapayati"]);
+    assert_has_tip(&[], &nic(&d("ga\\mx~", Bhvadi)), Lat, &["gamayati"]);
+    assert_has_tip(&[], &nic(&d_ghatadi("Gawa~\\", Bhvadi)), Lat, &["Gawayati"]);
+
+    // Compare to vrddhi for other dhatus.
+    assert_has_tip(&[], &nic(&d("qukf\\Y", Tanadi)), Lat, &["kArayati"]);
+    assert_has_tip(&[], &nic(&d("SIN", Adadi)), Lat, &["SAyayati"]);
+}
+
+#[test]
+fn num_agama_for_mucadi() {
+    // num-Agama by 7.1.59 applies only before Sa-vikarana.
+    let muc = d("mu\\cx~^", Tudadi);
+    assert_has_tip(&[], &muc, Lat, &["muYcati"]);
+    assert_has_ta(&[], &muc, Lat, &["muYcate"]);
+    assert_has_tip(&[], &muc, Lan, &["amuYcat"]);
+    assert_has_tip(&[], &muc, Lit, &["mumoca"]);
+    assert_has_tas(&[], &muc, Lit, &["mumucatuH"]);
+    assert_has_ta(&[], &muc, Lit, &["mumuce"]);
+
+    let lip = d("li\\pa~^", Tudadi);
+    assert_has_tip(&[], &lip, Lat, &["limpati"]);
+    assert_has_ta(&[], &lip, Lat, &["limpate"]);
+    assert_has_tip(&[], &lip, Lan, &["alimpat"]);
+    assert_has_tip(&[], &lip, Lit, &["lilepa"]);
+    assert_has_tas(&[], &lip, Lit, &["lilipatuH"]);
+    assert_has_ta(&[], &lip, Lit, &["lilipe"]);
+
+    let sic = d("zi\\ca~^", Tudadi);
+    assert_has_tip(&[], &sic, Lat, &["siYcati"]);
+    assert_has_ta(&[], &sic, Lat, &["siYcate"]);
+    assert_has_tip(&[], &sic, Lan, &["asiYcat"]);
+    assert_has_tip(&[], &sic, Lit, &["sizeca"]);
+    assert_has_tas(&[], &sic, Lit, &["sizicatuH"]);
+    assert_has_ta(&[], &sic, Lit, &["sizice"]);
+
+    // No num-Agama outside of Sa-vikarana.
+    assert_has_krdanta(&[], &muc, Krt::kta, &["mukta"]);
+    assert_has_tip(&[], &sic, Lrt, &["sekzyati"]);
+}
+
+#[test]
+fn tumun_with_it_agama() {
+    // anit roots (7.2.10).
+    assert_has_krdanta(&[], &d("qukf\\Y", Tanadi), Krt::tumun, &["kartum"]);
+    assert_has_krdanta(&[], &d("Bi\\di~^r", Rudhadi), Krt::tumun, &["Bettum"]);
+    assert_has_krdanta(&[], &d("ga\\mx~", Bhvadi), Krt::tumun, &["gantum"]);
+    assert_has_krdanta(&[], &d("df\\Si~r", Bhvadi), Krt::tumun, &["drazwum"]);
+
+    // set roots (7.2.35).
+    assert_has_krdanta(&[], &d("SIN", Adadi), Krt::tumun, &["Sayitum"]);
+    assert_has_krdanta(&[], &d("BU", Bhvadi), Krt::tumun, &["Bavitum"]);
+    assert_has_krdanta(&[], &d("zRu", Adadi), Krt::tumun, &["snavitum"]);
+
+    // Long iw by 7.2.37 and 7.2.38.
+    assert_has_krdanta(&[], &d("graha~^", Kryadi), Krt::tumun, &["grahItum"]);
+    assert_has_krdanta(&[], &d("tF", Bhvadi), Krt::tumun, &["tarItum", "taritum"]);
+
+    // vet roots (7.2.44).
+    assert_has_krdanta(
+        &[],
+        &d("gAhU~\\", Bhvadi),
+        Krt::tumun,
+        &["gAQum", "gAhitum"],
+    );
+    assert_has_krdanta(
+        &[],
+        &d("mfjU~", Adadi),
+        Krt::tumun,
+        &["mArzwum", "mArjitum"],
+    );
+}
+
+#[test]
+fn juhotyadi_lat() {
+    // Slu of Sap (2.4.75), dvitva (6.1.10), and jhi --> ati (7.1.4).
+    let assert_has_lat_parasmai = |dhatu: &Dhatu, expected: [&str; 9]| {
+        assert_has_tip(&[], dhatu, Lat, &[expected[0]]);
+        assert_has_tas(&[], dhatu, Lat, &[expected[1]]);
+        assert_has_jhi(&[], dhatu, Lat, &[expected[2]]);
+        assert_has_sip(&[], dhatu, Lat, &[expected[3]]);
+        assert_has_thas(&[], dhatu, Lat, &[expected[4]]);
+        assert_has_tha(&[], dhatu, Lat, &[expected[5]]);
+        assert_has_mip(&[], dhatu, Lat, &[expected[6]]);
+        assert_has_vas(&[], dhatu, Lat, &[expected[7]]);
+        assert_has_mas(&[], dhatu, Lat, &[expected[8]]);
+    };
+
+    assert_has_lat_parasmai(
+        &d("hu\\", Juhotyadi),
+        [
+            "juhoti", "juhutaH", "juhvati", "juhozi", "juhuTaH", "juhuTa", "juhomi", "juhuvaH",
+            "juhumaH",
+        ],
+    );
+    // A-lopa by 6.4.112.
+    assert_has_lat_parasmai(
+        &d("qudA\\Y", Juhotyadi),
+        [
+            "dadAti", "dattaH", "dadati", "dadAsi", "datTaH", "datTa", "dadAmi", "dadvaH", "dadmaH",
+        ],
+    );
+    // BaS-bhAva by 8.2.38.
+    assert_has_lat_parasmai(
+        &d("quDA\\Y", Juhotyadi),
+        [
+            "daDAti", "DattaH", "daDati", "daDAsi", "DatTaH", "DatTa", "daDAmi", "daDvaH", "daDmaH",
+        ],
+    );
+    // it-abhyAsa by 7.4.76.
+    assert_has_lat_parasmai(
+        &d("quBf\\Y", Juhotyadi),
+        [
+            "biBarti", "biBftaH", "biBrati", "biBarzi", "biBfTaH", "biBfTa", "biBarmi", "biBfvaH",
+            "biBfmaH",
+        ],
+    );
+}
+
+#[test]
+fn sarvanama_and_sankhya_dvivacana() {
+    // O --> SI after a napuMsaka anga (7.1.19) and after an AbantA anga (7.1.18).
+    assert_has_sup_1d("tad", Pum, &["tO"]);
+    assert_has_sup_1d("tad", Stri, &["te"]);
+    assert_has_sup_1d("tad", Napumsaka, &["te"]);
+    assert_has_sup_2d("tad", Pum, &["tO"]);
+    assert_has_sup_2d("tad", Stri, &["te"]);
+    assert_has_sup_2d("tad", Napumsaka, &["te"]);
+    assert_has_sup_3d("tad", Pum, &["tAByAm"]);
+    assert_has_sup_6d("tad", Stri, &["tayoH"]);
+
+    assert_has_sup_1d("yad", Pum, &["yO"]);
+    assert_has_sup_1d("yad", Napumsaka, &["ye"]);
+
+    // idam --> ima (7.2.109), including after num-Agama (7.1.72).
+    assert_has_sup_1d("idam", Pum, &["imO"]);
+    assert_has_sup_1d("idam", Stri, &["ime"]);
+    assert_has_sup_1d("idam", Napumsaka, &["ime"]);
+    assert_has_sup_1p("idam", Napumsaka, &["imAni"]);
+    assert_has_sup_2p("idam", Napumsaka, &["imAni"]);
+
+    assert_has_sup_1d("dvi", Pum, &["dvO"]);
+    assert_has_sup_1d("dvi", Stri, &["dve"]);
+    assert_has_sup_1d("dvi", Napumsaka, &["dve"]);
+    assert_has_sup_3d("dvi", Pum, &["dvAByAm"]);
+    assert_has_sup_6d("dvi", Pum, &["dvayoH"]);
+}
+
+#[test]
+fn agent_nouns_in_stri() {
+    let kr = d("qukf\\Y", Tanadi);
+    let ni = d("RI\\Y", Bhvadi);
+
+    // Rvul takes wAp (4.1.4), then a --> i by 7.3.44.
+    assert_has_sup_1s(krdanta(&[], &kr, Krt::Rvul), Pum, &["kArakaH"]);
+    assert_has_sup_1s(krdanta(&[], &kr, Krt::Rvul), Stri, &["kArikA"]);
+    assert_has_sup_1p(krdanta(&[], &kr, Krt::Rvul), Stri, &["kArikAH"]);
+    assert_has_sup_1s(krdanta(&[], &ni, Krt::Rvul), Stri, &["nAyikA"]);
+
+    // tfc takes NIp (4.1.5).
+    assert_has_sup_1s(krdanta(&[], &kr, Krt::tfc), Pum, &["kartA"]);
+    assert_has_sup_1s(krdanta(&[], &kr, Krt::tfc), Stri, &["kartrI"]);
+    assert_has_sup_1d(krdanta(&[], &kr, Krt::tfc), Stri, &["kartryO"]);
+    assert_has_sup_1s(krdanta(&[], &ni, Krt::tfc), Stri, &["netrI"]);
+}
+
+#[test]
+fn rudhadi_lat() {
+    // Snam (3.1.78), Snaso lopa in weak forms (6.4.111), and optional jhar-lopa (8.4.65).
+    let assert_has_lat_parasmai = |dhatu: &Dhatu, expected: [&[&str]; 9]| {
+        assert_has_tip(&[], dhatu, Lat, expected[0]);
+        assert_has_tas(&[], dhatu, Lat, expected[1]);
+        assert_has_jhi(&[], dhatu, Lat, expected[2]);
+        assert_has_sip(&[], dhatu, Lat, expected[3]);
+        assert_has_thas(&[], dhatu, Lat, expected[4]);
+        assert_has_tha(&[], dhatu, Lat, expected[5]);
+        assert_has_mip(&[], dhatu, Lat, expected[6]);
+        assert_has_vas(&[], dhatu, Lat, expected[7]);
+        assert_has_mas(&[], dhatu, Lat, expected[8]);
+    };
+
+    assert_has_lat_parasmai(
+        &d("ru\\Di~^r", Rudhadi),
+        [
+            &["ruRadDi"],
+            &["runDaH", "rundDaH"],
+            &["runDanti"],
+            &["ruRatsi"],
+            &["runDaH", "rundDaH"],
+            &["runDa", "rundDa"],
+            &["ruRaDmi"],
+            &["runDvaH"],
+            &["runDmaH"],
+        ],
+    );
+    assert_has_lat_parasmai(
+        &d("Bi\\di~^r", Rudhadi),
+        [
+            &["Binatti"],
+            &["BintaH", "BinttaH"],
+            &["Bindanti"],
+            &["Binatsi"],
+            &["BinTaH", "BintTaH"],
+            &["BinTa", "BintTa"],
+            &["Binadmi"],
+            &["BindvaH"],
+            &["BindmaH"],
+        ],
+    );
+    assert_has_lat_parasmai(
+        &d("yu\\ji~^r", Rudhadi),
+        [
+            &["yunakti"],
+            &["yuNktaH"],
+            &["yuYjanti"],
+            &["yunakzi"],
+            &["yuNkTaH"],
+            &["yuNkTa"],
+            &["yunajmi"],
+            &["yuYjvaH"],
+            &["yuYjmaH"],
+        ],
+    );
+}
+
+#[test]
+fn lot_sip_and_tip() {
+    // sip: hi-luk after a (6.4.105), hi --> Di (6.4.101), and tAtaN (7.1.35).
+    assert_has_sip(&[], &d("ga\\mx~", Bhvadi), Lot, &["gacCa", "gacCatAt"]);
+    assert_has_sip(&[], &d("BU", Bhvadi), Lot, &["Bava", "BavatAt"]);
+    assert_has_sip(&[], &d("hu\\", Juhotyadi), Lot, &["juhuDi", "juhutAt"]);
+    // han --> ja (6.4.36).
+    assert_has_sip(&[], &d("ha\\na~", Adadi), Lot, &["jahi", "hatAt"]);
+    // Ga-Adesha and abhyAsa-lopa (6.4.119).
+    assert_has_sip(&[], &d("quDA\\Y", Juhotyadi), Lot, &["Dehi", "DattAt"]);
+    // as --> e (6.4.119) and Di (6.4.101).
+    assert_has_sip(&[], &d("asa~", Adadi), Lot, &["eDi", "stAt"]);
+
+    // tip: ti --> tu (3.4.86) and tAtaN (7.1.35).
+    assert_has_tip(&[], &d("ga\\mx~", Bhvadi), Lot, &["gacCatu", "gacCatAt"]);
+    assert_has_tip(&[], &d("ha\\na~", Adadi), Lot, &["hantu", "hatAt"]);
+    assert_has_tip(&[], &d("quDA\\Y", Juhotyadi), Lot, &["daDAtu", "DattAt"]);
+    assert_has_tip(&[], &d("asa~", Adadi), Lot, &["astu", "stAt"]);
+}
+
+#[test]
+fn as_bhuvi_lat_lan_and_vidhilin() {
+    let assert_has_parasmai = |dhatu: &Dhatu, la: Lakara, expected: [&str; 9]| {
+        assert_has_tip(&[], dhatu, la, &[expected[0]]);
+        assert_has_tas(&[], dhatu, la, &[expected[1]]);
+        assert_has_jhi(&[], dhatu, la, &[expected[2]]);
+        assert_has_sip(&[], dhatu, la, &[expected[3]]);
+        assert_has_thas(&[], dhatu, la, &[expected[4]]);
+        assert_has_tha(&[], dhatu, la, &[expected[5]]);
+        assert_has_mip(&[], dhatu, la, &[expected[6]]);
+        assert_has_vas(&[], dhatu, la, &[expected[7]]);
+        assert_has_mas(&[], dhatu, la, &[expected[8]]);
+    };
+
+    let as_ = d("asa~", Adadi);
+    // a-lopa in weak forms (6.4.111) and s-lopa before s (7.4.50).
+    assert_has_parasmai(
+        &as_,
+        Lat,
+        [
+            "asti", "staH", "santi", "asi", "sTaH", "sTa", "asmi", "svaH", "smaH",
+        ],
+    );
+    // Iw-Agama before tip and sip (7.3.96) and Aw-Agama (6.4.72).
+    assert_has_parasmai(
+        &as_,
+        Lan,
+        [
+            "AsIt", "AstAm", "Asan", "AsIH", "Astam", "Asta", "Asam", "Asva", "Asma",
+        ],
+    );
+    // yAsu~w-Agama (3.4.103) with a-lopa (6.4.111).
+    assert_has_parasmai(
+        &as_,
+        VidhiLin,
+        [
+            "syAt", "syAtAm", "syuH", "syAH", "syAtam", "syAta", "syAm", "syAva", "syAma",
+        ],
+    );
+    // BU-Adesha in ArdhadhAtuka (2.4.52).
+    assert_has_tip(&[], &as_, Lut, &["BavitA"]);
+    assert_has_tip(&[], &as_, Lrt, &["Bavizyati"]);
+}
+
+#[test]
+fn kvip_and_kvin_subantas() {
+    // 6.1.67 must precede 6.4.77.
+    let shri = krdanta(&[], &d("SriY", Bhvadi), Krt::kvip);
+    assert_has_sup_1s(&shri, Stri, &["SrIH"]);
+    assert_has_sup_1d(&shri, Stri, &["SriyO"]);
+    let ni = krdanta(&[], &d("RI\\Y", Bhvadi), Krt::kvip);
+    assert_has_sup_1d(&ni, Pum, &["niyO"]);
+
+    // 6.4.24 applies even if kvin is deleted by 6.1.66.
+    let prac = krdanta(&["pra"], &d("ancu~", Bhvadi), Krt::kvin);
+    assert_has_sup_1s(&prac, Pum, &["prAN"]);
+    assert_has_sup_1d(&prac, Pum, &["prAYcO"]);
+    assert_has_sup_2p(&prac, Pum, &["prAcaH"]);
+    assert_has_sup_3p(&prac, Pum, &["prAgBiH"]);
+}
+
+#[test]
+fn bhave_prayoga() {
+    let bhu = d("BU", Bhvadi);
+    let svap = d("Yizva\\pa~", Adadi);
+    let gam = d("ga\\mx~", Bhvadi);
+
+    assert_has_ta_bhave(&[], &bhu, Lat, &["BUyate"]);
+    assert_has_ta_bhave(&[], &svap, Lat, &["supyate"]);
+    assert_has_ta_bhave(&[], &gam, Lat, &["gamyate"]);
+
+    // ciR (3.1.66)
+    assert_has_ta_bhave(&[], &bhu, Lun, &["aBAvi"]);
+    assert_has_ta_bhave(&[], &svap, Lun, &["asvApi"]);
+    assert_has_ta_bhave(&[], &gam, Lun, &["agAmi"]);
+
+    // bhAve is used only in prathama-puruSa ekavacana.
+    let v = Vyakarana::new();
+    let args = Tinanta::builder()
+        .dhatu(bhu)
+        .prayoga(Prayoga::Bhave)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Dvi)
+        .lakara(Lat)
+        .build()
+        .unwrap();
+    assert!(v.derive_tinantas(&args).is_empty());
+}
+
+#[test]
+fn ghan_vrddhi() {
+    assert_has_krdanta(&[], &d("qupa\\ca~^z", Bhvadi), Krt::GaY, &["pAka"]);
+    assert_has_krdanta(&[], &d("tya\\ja~", Bhvadi), Krt::GaY, &["tyAga"]);
+    assert_has_krdanta(&[], &d("BU", Bhvadi), Krt::GaY, &["BAva"]);
+    assert_has_krdanta(&[], &d("Bu\\ja~", Rudhadi), Krt::GaY, &["Boga"]);
+    assert_has_krdanta(&[], &d("kamu~\\", Bhvadi), Krt::GaY, &["kAma"]);
+    // 6.4.27 (optional nasal loss)
+    assert_has_krdanta(&[], &d("ra\\nja~^", Bhvadi), Krt::GaY, &["rAga", "raNga"]);
+}
+
+#[test]
+fn shatr_and_shanac_with_vikaranas() {
+    // Bhvadi (7.1.81: num is required)
+    let gacchat = krdanta(&[], &d("ga\\mx~", Bhvadi), Krt::Satf);
+    assert_has_sup_1s(&gacchat, Pum, &["gacCan"]);
+    assert_has_sup_1s(&gacchat, Stri, &["gacCantI"]);
+    let pashyat = krdanta(&[], &d("df\\Si~r", Bhvadi), Krt::Satf);
+    assert_has_sup_1s(&pashyat, Pum, &["paSyan"]);
+    assert_has_sup_1s(&pashyat, Stri, &["paSyantI"]);
+
+    // Tanadi (no num for the feminine)
+    let kurvat = krdanta(&[], &d("qukf\\Y", Tanadi), Krt::Satf);
+    assert_has_sup_1s(&kurvat, Pum, &["kurvan"]);
+    assert_has_sup_1s(&kurvat, Stri, &["kurvatI"]);
+
+    // Juhotyadi (7.1.78: no num at all, and 7.1.80 is blocked as well)
+    let juhvat = krdanta(&[], &d("hu\\", Juhotyadi), Krt::Satf);
+    assert_has_sup_1s(&juhvat, Pum, &["juhvat"]);
+    assert_has_sup_1s(&juhvat, Stri, &["juhvatI"]);
+    let dadhat = krdanta(&[], &d("quDA\\Y", Juhotyadi), Krt::Satf);
+    assert_has_sup_1s(&dadhat, Pum, &["daDat"]);
+    assert_has_sup_1s(&dadhat, Stri, &["daDatI"]);
+
+    // Atmanepada
+    let labhamana = krdanta(&[], &d("qulaBa~\\z", Bhvadi), Krt::SAnac);
+    assert_has_sup_1s(&labhamana, Pum, &["laBamAnaH"]);
+    assert_has_sup_1s(&labhamana, Stri, &["laBamAnA"]);
+    let kurvana = krdanta(&[], &d("qukf\\Y", Tanadi), Krt::SAnac);
+    assert_has_sup_1s(&kurvana, Pum, &["kurvARaH"]);
+    assert_has_sup_1s(&kurvana, Stri, &["kurvARA"]);
+    let bibhrana = krdanta(&[], &d("quBf\\Y", Juhotyadi), Krt::SAnac);
+    assert_has_sup_1s(&bibhrana, Pum, &["biBrARaH"]);
+    assert_has_sup_1s(&bibhrana, Stri, &["biBrARA"]);
+}
+
+#[test]
+fn at_agama_for_vowel_initial_dhatus() {
+    let arc = d("arca~", Bhvadi);
+    let iks = d("Ikza~\\", Bhvadi);
+    let edh = d("eDa~\\", Bhvadi);
+
+    // 6.4.72 (Aw) + 6.1.90 (vrddhi)
+    assert_has_tip(&[], &arc, Lan, &["Arcat"]);
+    assert_has_ta(&[], &iks, Lan, &["Ekzata"]);
+    assert_has_ta(&[], &edh, Lan, &["EData"]);
+    assert_has_tip(&[], &d("ata~", Bhvadi), Lan, &["Atat"]);
+    assert_has_tip(&[], &d("uzi~", Bhvadi), Lan, &["OMzat"]);
+    assert_has_tip(&[], &nic(&d("Ira~\\", Adadi)), Lan, &["Erayat"]);
+    assert_has_tip(&[], &d("A\\px~", Svadi), Lan, &["Apnot"]);
+
+    // luN and lfN
+    assert_has_tip(&[], &arc, Lun, &["ArcIt"]);
+    assert_has_ta(&[], &iks, Lun, &["Ekzizwa"]);
+    assert_has_ta(&[], &edh, Lun, &["EDizwa"]);
+    assert_has_tip(&[], &arc, Lrn, &["Arcizyat"]);
+    assert_has_ta(&[], &iks, Lrn, &["Ekzizyata"]);
+    assert_has_ta(&[], &edh, Lrn, &["EDizyata"]);
+
+    // With upasargas, Aw is added after the upasarga.
+    assert_has_tip(&["pra"], &arc, Lan, &["prArcat"]);
+    assert_has_ta(&["upa"], &iks, Lan, &["upEkzata"]);
+    assert_has_ta(&["aBi"], &edh, Lan, &["aByEData"]);
+}
+
+#[test]
+fn lun_vikaranas() {
+    // sic (3.1.44)
+    let kr = d("qukf\\Y", Tanadi);
+    assert_has_tip(&[], &kr, Lun, &["akArzIt"]);
+    assert_has_jhi(&[], &kr, Lun, &["akArzuH"]);
+    assert_has_ta(&[], &kr, Lun, &["akfta"]);
+    assert_has_tip(&[], &d("RI\\Y", Bhvadi), Lun, &["anEzIt"]);
+    assert_has_tip(&[], &d("dA\\p", Adadi), Lun, &["adAsIt"]);
+    assert_has_jhi(&[], &d("dA\\p", Adadi), Lun, &["adAsizuH"]);
+    let chid = d("Ci\\di~^r", Rudhadi);
+    assert_has_tip(&[], &chid, Lun, &["acCEtsIt", "acCidat"]);
+    assert_has_ta(&[], &chid, Lun, &["acCitta"]);
+
+    // sic-luk (2.4.77)
+    assert_has_tip(&[], &d("qudA\\Y", Juhotyadi), Lun, &["adAt"]);
+    assert_has_tip(&[], &d("BU", Bhvadi), Lun, &["aBUt"]);
+
+    // ksa (3.1.45)
+    assert_has_tip(&[], &d("di\\Sa~^", Tudadi), Lun, &["adikzat"]);
+
+    // caN (3.1.48)
+    assert_has_tip(&[], &nic(&kr), Lun, &["acIkarat"]);
+    assert_has_tip(&[], &nic(&d("BU", Bhvadi)), Lun, &["abIBavat"]);
+    assert_has_tip(&[], &d("Sri\\Y", Bhvadi), Lun, &["aSiSriyat"]);
+
+    // aN (3.1.52 - 3.1.59)
+    assert_has_tip(&[], &d("va\\ca~", Adadi), Lun, &["avocat"]);
+    assert_has_tip(&[], &d("ga\\mx~", Bhvadi), Lun, &["agamat"]);
+    assert_has_tip(&[], &d("asu~", Divadi), Lun, &["AsTat"]);
+    assert_has_tip(&[], &d("sf\\", Juhotyadi), Lun, &["asarat"]);
+}
+
+#[test]
+fn samprasarana_for_grahi_jya_and_vaci_svapi() {
+    let grah = d("graha~^", Kryadi);
+    let prach = d("pra\\Ca~", Tudadi);
+    let vrasc = d("o~vrascU~", Tudadi);
+    let svap = d("Yizva\\pa~", Adadi);
+
+    // kta is kit (6.1.15, 6.1.16)
+    assert_has_krdanta(&[], &grah, Krt::kta, &["gfhIta"]);
+    assert_has_krdanta(&[], &prach, Krt::kta, &["pfzwa"]);
+    assert_has_krdanta(&[], &vrasc, Krt::kta, &["vfkRa"]);
+    assert_has_krdanta(&[], &svap, Krt::kta, &["supta"]);
+
+    // yak is kit
+    assert_has_ta_k(&[], &grah, Lat, &["gfhyate"]);
+    assert_has_ta_k(&[], &prach, Lat, &["pfcCyate"]);
+    assert_has_ta_k(&[], &vrasc, Lat, &["vfScyate"]);
+    assert_has_ta_k(&[], &svap, Lat, &["supyate"]);
+
+    // Sa and SnA are Nit (1.2.4)
+    assert_has_tip(&[], &grah, Lat, &["gfhRAti"]);
+    assert_has_tip(&[], &prach, Lat, &["pfcCati"]);
+    assert_has_tip(&[], &vrasc, Lat, &["vfScati"]);
+
+    // No samprasarana before an ardhadhatuka that is not kit or Nit.
+    assert_has_krdanta(&[], &grah, Krt::tfc, &["grahItf"]);
+    assert_has_krdanta(&[], &prach, Krt::tfc, &["prazwf"]);
+    assert_has_krdanta(&[], &svap, Krt::tfc, &["svaptf"]);
+    // 7.2.55 makes ktvA sew, so it is not kit by 1.2.18.
+    assert_has_krdanta(&[], &vrasc, Krt::ktvA, &["vraScitvA"]);
+    // lyap
+    assert_has_krdanta(&["pra"], &grah, Krt::ktvA, &["pragfhya"]);
+    assert_has_krdanta(&["A"], &prach, Krt::ktvA, &["ApfcCya"]);
+}
+
+#[test]
+fn matvartha_taddhitas_with_stri() {
+    use vidyut_prakriya::args::Taddhita as T;
+
+    // matup with 8.2.9 (mAdupaDAyAH) and 8.2.10 (Jayas)
+    let dhana = taddhitanta("Dana", T::matup);
+    assert_has_sup_1s(&dhana, Pum, &["DanavAn"]);
+    assert_has_sup_1s(&dhana, Stri, &["DanavatI"]);
+    let payas = taddhitanta("payas", T::matup);
+    assert_has_sup_1s(&payas, Pum, &["payasvAn"]);
+    assert_has_sup_1s(&payas, Stri, &["payasvatI"]);
+    let vidyut = taddhitanta("vidyut", T::matup);
+    assert_has_sup_1s(&vidyut, Pum, &["vidyutvAn"]);
+    assert_has_sup_1s(&vidyut, Stri, &["vidyutvatI"]);
+
+    // matup stays as is
+    let shri = taddhitanta("SrI", T::matup);
+    assert_has_sup_1s(&shri, Pum, &["SrImAn"]);
+    assert_has_sup_1s(&shri, Stri, &["SrImatI"]);
+    let agni = taddhitanta("agni", T::matup);
+    assert_has_sup_1s(&agni, Pum, &["agnimAn"]);
+    assert_has_sup_1s(&agni, Stri, &["agnimatI"]);
+    // yavAdi (8.2.9)
+    assert_has_sup_1s(taddhitanta("yava", T::matup), Pum, &["yavamAn"]);
+
+    // vini (5.2.121)
+    let medha = taddhitanta("meDA", T::vini);
+    assert_has_sup_1s(&medha, Pum, &["meDAvI"]);
+    assert_has_sup_1s(&medha, Stri, &["meDAvinI"]);
+    let yashas = taddhitanta("yaSas", T::vini);
+    assert_has_sup_1s(&yashas, Pum, &["yaSasvI"]);
+    assert_has_sup_1s(&yashas, Stri, &["yaSasvinI"]);
+
+    // ini (5.2.115)
+    let danda = taddhitanta("daRqa", T::ini);
+    assert_has_sup_1s(&danda, Pum, &["daRqI"]);
+    assert_has_sup_1s(&danda, Stri, &["daRqinI"]);
+}
+
+#[test]
+fn lyap_with_tuk_and_anunasika_lopa() {
+    // tuk for a hrasva-final dhatu (6.1.71)
+    assert_has_krdanta(&["prati"], &d("i\\R", Adadi), Krt::ktvA, &["pratItya"]);
+    assert_has_krdanta(&["A"], &d("Sru\\", Svadi), Krt::ktvA, &["ASrutya"]);
+    assert_has_krdanta(&["pra"], &d("qukf\\Y", Tanadi), Krt::ktvA, &["prakftya"]);
+    // No tuk otherwise.
+    assert_has_krdanta(&["A"], &d("ru\\ha~", Bhvadi), Krt::ktvA, &["Aruhya"]);
+
+    // Optional lopa for a dhatu ending in m (6.4.38), then tuk.
+    assert_has_krdanta(
+        &["sam"],
+        &d("ga\\mx~", Bhvadi),
+        Krt::ktvA,
+        &["saNgatya", "saNgamya"],
+    );
+    assert_has_krdanta(
+        &["A"],
+        &d("ga\\mx~", Bhvadi),
+        Krt::ktvA,
+        &["Agatya", "Agamya"],
+    );
+    // Required lopa for a dhatu ending in n.
+    assert_has_krdanta(&["A"], &d("ha\\na~", Adadi), Krt::ktvA, &["Ahatya"]);
+    assert_has_krdanta(&["vi"], &d("tanu~^", Tanadi), Krt::ktvA, &["vitatya"]);
+}
+
+#[test]
+fn am_pratyaya_in_lit() {
+    let kr = d("qukf\\Y", Tanadi);
+    let gam = d("ga\\mx~", Bhvadi);
+
+    // Ric (3.1.35)
+    assert_has_tip(
+        &[],
+        &nic(&kr),
+        Lit,
+        &["kArayAYcakAra", "kArayAmAsa", "kArayAmbaBUva"],
+    );
+    assert_has_tip(
+        &[],
+        &nic(&gam),
+        Lit,
+        &["gamayAYcakAra", "gamayAmAsa", "gamayAmbaBUva"],
+    );
+    // The anuprayoga of kf follows the pada of the main dhatu (1.3.63).
+    assert_has_ta(
+        &[],
+        &nic(&kr),
+        Lit,
+        &["kArayAYcakre", "kArayAmAsa", "kArayAmbaBUva"],
+    );
+
+    // san (3.1.35)
+    assert_has_tip(
+        &[],
+        &san(&kr),
+        Lit,
+        &["cikIrzAYcakAra", "cikIrzAmAsa", "cikIrzAmbaBUva"],
+    );
+    assert_has_tip(
+        &[],
+        &san(&gam),
+        Lit,
+        &["jigamizAYcakAra", "jigamizAmAsa", "jigamizAmbaBUva"],
+    );
+
+    // ijAdi and gurumAn (3.1.36)
+    assert_has_ta(
+        &[],
+        &d("Iha~\\", Bhvadi),
+        Lit,
+        &["IhAYcakre", "IhAmAsa", "IhAmbaBUva"],
+    );
+    // day, ay, As (3.1.37)
+    assert_has_ta(
+        &[],
+        &d("daya~\\", Bhvadi),
+        Lit,
+        &["dayAYcakre", "dayAmAsa", "dayAmbaBUva"],
+    );
+}
+
+#[test]
+fn ktavatu_subantas() {
+    let kr = d("qukf\\Y", Tanadi);
+    let kftavat = krdanta(&[], &kr, Krt::ktavatu);
+    assert_has_krdanta(&[], &kr, Krt::ktavatu, &["kftavat"]);
+    assert_has_sup_1s(&kftavat, Pum, &["kftavAn"]);
+    assert_has_sup_1p(&kftavat, Pum, &["kftavantaH"]);
+    assert_has_sup_1s(&kftavat, Stri, &["kftavatI"]);
+    assert_has_sup_1s(&kftavat, Napumsaka, &["kftavat"]);
+
+    let gatavat = krdanta(&[], &d("ga\\mx~", Bhvadi), Krt::ktavatu);
+    assert_has_sup_1s(&gatavat, Pum, &["gatavAn"]);
+    assert_has_sup_1s(&gatavat, Stri, &["gatavatI"]);
+
+    // With na-Adesha (8.2.44)
+    let lunavat = krdanta(&[], &d("lUY", Kryadi), Krt::ktavatu);
+    assert_has_sup_1s(&lunavat, Pum, &["lUnavAn"]);
+    assert_has_sup_1s(&lunavat, Stri, &["lUnavatI"]);
+}
+
+#[test]
+fn ha_adesha_and_bhash_for_duh_and_lih() {
+    let duh = d("du\\ha~^", Adadi);
+    let lih = d("li\\ha~^", Adadi);
+
+    // kta, with Ga by 8.2.32 and Qa by 8.2.31.
+    assert_has_krdanta(&[], &duh, Krt::kta, &["dugDa"]);
+    assert_has_krdanta(&[], &lih, Krt::kta, &["lIQa"]);
+    assert_has_krdanta(&[], &duh, Krt::tfc, &["dogDf"]);
+    assert_has_krdanta(&[], &lih, Krt::tfc, &["leQf"]);
+
+    // law, with Bazadeza by 8.2.37.
+    assert_has_tip(&[], &duh, Lat, &["dogDi"]);
+    assert_has_tas(&[], &duh, Lat, &["dugDaH"]);
+    assert_has_sip(&[], &duh, Lat, &["Dokzi"]);
+    assert_has_dhvam(&[], &duh, Lat, &["DugDve"]);
+    assert_has_tip(&[], &lih, Lat, &["leQi"]);
+    assert_has_tas(&[], &lih, Lat, &["lIQaH"]);
+    assert_has_sip(&[], &lih, Lat, &["lekzi"]);
+    assert_has_dhvam(&[], &lih, Lat, &["lIQve"]);
+
+    // liw
+    assert_has_tip(&[], &duh, Lit, &["dudoha"]);
+    assert_has_sip(&[], &duh, Lit, &["dudohiTa"]);
+    assert_has_tip(&[], &lih, Lit, &["lileha"]);
+    assert_has_sip(&[], &lih, Lit, &["lilehiTa"]);
+}
+
+#[test]
+fn lit_for_ac_adi_and_a_upadha_dhatus() {
+    // nuw-Agama for the abhyasa (7.4.71, 7.4.72)
+    let arc = d("arca~", Bhvadi);
+    assert_has_tip(&[], &arc, Lit, &["Anarca"]);
+    assert_has_jhi(&[], &arc, Lit, &["AnarcuH"]);
+    let ash = d("aSU~\\", Svadi);
+    assert_has_ta(&[], &ash, Lit, &["AnaSe"]);
+    assert_has_jha(&[], &ash, Lit, &["AnaSire"]);
+    let rdh = d("fDu~", Svadi);
+    assert_has_tip(&[], &rdh, Lit, &["AnarDa"]);
+    assert_has_jhi(&[], &rdh, Lit, &["AnfDuH"]);
+
+    // No nuw-Agama otherwise (7.4.70)
+    let ash_kryadi = d("aSa~", Kryadi);
+    assert_has_tip(&[], &ash_kryadi, Lit, &["ASa"]);
+    assert_has_jhi(&[], &ash_kryadi, Lit, &["ASuH"]);
+
+    // f (7.4.11, 7.2.66)
+    let r = d("f\\", Bhvadi);
+    assert_has_tip(&[], &r, Lit, &["Ara"]);
+    assert_has_tas(&[], &r, Lit, &["AratuH"]);
+    assert_has_sip(&[], &r, Lit, &["AriTa"]);
+
+    // e-Adesha and abhyasa-lopa (6.4.120, 6.4.121)
+    let pac = d("qupa\\ca~^z", Bhvadi);
+    assert_has_tip(&[], &pac, Lit, &["papAca"]);
+    assert_has_tas(&[], &pac, Lit, &["pecatuH"]);
+    assert_has_sip(&[], &pac, Lit, &["papakTa", "peciTa"]);
+    let pat = d("patx~", Bhvadi);
+    assert_has_tip(&[], &pat, Lit, &["papAta"]);
+    assert_has_sip(&[], &pat, Lit, &["petiTa"]);
+}
+
+#[test]
+fn tva_and_tal_with_linga() {
+    use vidyut_prakriya::args::Taddhita as T;
+
+    let mahattva = taddhitanta("mahat", T::tva);
+    assert_has_sup_1s(&mahattva, Napumsaka, &["mahattvam"]);
+    let mahatta = taddhitanta("mahat", T::tal);
+    assert_has_sup_1s(&mahatta, Stri, &["mahattA"]);
+
+    assert_has_sup_1s(taddhitanta("laGu", T::tva), Napumsaka, &["laGutvam"]);
+    assert_has_sup_1s(taddhitanta("laGu", T::tal), Stri, &["laGutA"]);
+}
+
+#[test]
+fn f_stems() {
+    // pitf (no upadhA-dIrgha)
+    assert_has_sup_1s("pitf", Pum, &["pitA"]);
+    assert_has_sup_1p("pitf", Pum, &["pitaraH"]);
+    assert_has_sup_2s("pitf", Pum, &["pitaram"]);
+    assert_has_sup_2p("pitf", Pum, &["pitFn"]);
+    assert_has_sup_6p("pitf", Pum, &["pitFRAm"]);
+
+    // kartf (upadhA-dIrgha by 6.4.11, which requires tfc)
+    let kartf = krdanta(&[], &d("qukf\\Y", Tanadi), Krt::tfc);
+    assert_has_sup_1s(&kartf, Pum, &["kartA"]);
+    assert_has_sup_1p(&kartf, Pum, &["kartAraH"]);
+    assert_has_sup_2s(&kartf, Pum, &["kartAram"]);
+    assert_has_sup_2p(&kartf, Pum, &["kartFn"]);
+    assert_has_sup_6p(&kartf, Pum, &["kartFRAm"]);
+
+    // mAtf (stri, so no n by 6.1.103)
+    assert_has_sup_1s("mAtf", Stri, &["mAtA"]);
+    assert_has_sup_1p("mAtf", Stri, &["mAtaraH"]);
+    assert_has_sup_2s("mAtf", Stri, &["mAtaram"]);
+    assert_has_sup_2p("mAtf", Stri, &["mAtFH"]);
+    assert_has_sup_6p("mAtf", Stri, &["mAtFRAm"]);
+
+    // svasf and naptf (6.4.11)
+    assert_has_sup_1p("svasf", Stri, &["svasAraH"]);
+    assert_has_sup_2p("svasf", Stri, &["svasFH"]);
+    assert_has_sup_1p("naptf", Pum, &["naptAraH"]);
+}
+
+#[test]
+fn tanadi_u_vikarana_in_lat() {
+    // Optional u-lopa before m and v (6.4.107)
+    let tan = d("tanu~^", Tanadi);
+    assert_has_tip(&[], &tan, Lat, &["tanoti"]);
+    assert_has_tas(&[], &tan, Lat, &["tanutaH"]);
+    assert_has_jhi(&[], &tan, Lat, &["tanvanti"]);
+    assert_has_sip(&[], &tan, Lat, &["tanozi"]);
+    assert_has_thas(&[], &tan, Lat, &["tanuTaH"]);
+    assert_has_tha(&[], &tan, Lat, &["tanuTa"]);
+    assert_has_mip(&[], &tan, Lat, &["tanomi"]);
+    assert_has_vas(&[], &tan, Lat, &["tanuvaH", "tanvaH"]);
+    assert_has_mas(&[], &tan, Lat, &["tanumaH", "tanmaH"]);
+
+    assert_has_ta(&[], &tan, Lat, &["tanute"]);
+    assert_has_aataam(&[], &tan, Lat, &["tanvAte"]);
+    assert_has_jha(&[], &tan, Lat, &["tanvate"]);
+    assert_has_thaas(&[], &tan, Lat, &["tanuze"]);
+    assert_has_aathaam(&[], &tan, Lat, &["tanvATe"]);
+    assert_has_dhvam(&[], &tan, Lat, &["tanuDve"]);
+    assert_has_iw(&[], &tan, Lat, &["tanve"]);
+    assert_has_vahi(&[], &tan, Lat, &["tanuvahe", "tanvahe"]);
+    assert_has_mahin(&[], &tan, Lat, &["tanumahe", "tanmahe"]);
+
+    // kf: ut for the anga (6.4.110) and required u-lopa (6.4.108, 6.4.109)
+    let kr = d("qukf\\Y", Tanadi);
+    assert_has_tip(&[], &kr, Lat, &["karoti"]);
+    assert_has_tas(&[], &kr, Lat, &["kurutaH"]);
+    assert_has_jhi(&[], &kr, Lat, &["kurvanti"]);
+    assert_has_sip(&[], &kr, Lat, &["karozi"]);
+    assert_has_thas(&[], &kr, Lat, &["kuruTaH"]);
+    assert_has_tha(&[], &kr, Lat, &["kuruTa"]);
+    assert_has_mip(&[], &kr, Lat, &["karomi"]);
+    assert_has_vas(&[], &kr, Lat, &["kurvaH"]);
+    assert_has_mas(&[], &kr, Lat, &["kurmaH"]);
+
+    assert_has_ta(&[], &kr, Lat, &["kurute"]);
+    assert_has_aataam(&[], &kr, Lat, &["kurvAte"]);
+    assert_has_jha(&[], &kr, Lat, &["kurvate"]);
+    assert_has_thaas(&[], &kr, Lat, &["kuruze"]);
+    assert_has_aathaam(&[], &kr, Lat, &["kurvATe"]);
+    assert_has_dhvam(&[], &kr, Lat, &["kuruDve"]);
+    assert_has_iw(&[], &kr, Lat, &["kurve"]);
+    assert_has_vahi(&[], &kr, Lat, &["kurvahe"]);
+    assert_has_mahin(&[], &kr, Lat, &["kurmahe"]);
+
+    // u-lopa is also required before hi (6.4.106), except for kf.
+    assert_has_sip(&[], &kr, Lot, &["kuru", "kurutAt"]);
+    assert_has_sip(&[], &tan, Lot, &["tanu", "tanutAt"]);
+}
+
+#[test]
+fn anc_stems() {
+    let anc = d("ancu~", Bhvadi);
+
+    // 6.4.138 is a no-op for the `a` of `prAc`.
+    let prac = krdanta(&["pra"], &anc, Krt::kvin);
+    assert_has_sup_1s(&prac, Pum, &["prAN"]);
+    assert_has_sup_1p(&prac, Pum, &["prAYcaH"]);
+    assert_has_sup_3s(&prac, Pum, &["prAcA"]);
+    assert_has_sup_3p(&prac, Pum, &["prAgBiH"]);
+
+    // udIc- in bha (6.4.139).
+    let udac = krdanta(&["ud"], &anc, Krt::kvin);
+    assert_has_sup_1s(&udac, Pum, &["udaN"]);
+    assert_has_sup_1p(&udac, Pum, &["udaYcaH"]);
+    assert_has_sup_3s(&udac, Pum, &["udIcA"]);
+    assert_has_sup_3p(&udac, Pum, &["udagBiH"]);
+
+    // pratIc- in bha (6.4.138 + 6.3.138), even with an upasarga.
+    let pratyac = krdanta(&["prati"], &anc, Krt::kvin);
+    assert_has_sup_1s(&pratyac, Pum, &["pratyaN"]);
+    assert_has_sup_1p(&pratyac, Pum, &["pratyaYcaH"]);
+    assert_has_sup_3s(&pratyac, Pum, &["pratIcA"]);
+    assert_has_sup_7p(&pratyac, Pum, &["pratyakzu"]);
+}
+
+#[test]
+fn rini_with_upapada_and_in_stems() {
+    let bhuj = d("Bu\\ja~", Rudhadi);
+    let ushnabhojin = upapada_krdanta("uzRa", &[], &bhuj, Krt::Rini);
+    assert_has_sup_1s(&ushnabhojin, Pum, &["uzRaBojI"]);
+    assert_has_sup_1d(&ushnabhojin, Pum, &["uzRaBojinO"]);
+    assert_has_sup_1p(&ushnabhojin, Pum, &["uzRaBojinaH"]);
+    assert_has_sup_2s(&ushnabhojin, Pum, &["uzRaBojinam"]);
+    assert_has_sup_3s(&ushnabhojin, Pum, &["uzRaBojinA"]);
+    assert_has_sup_3p(&ushnabhojin, Pum, &["uzRaBojiBiH"]);
+    assert_has_sup_6p(&ushnabhojin, Pum, &["uzRaBojinAm"]);
+    assert_has_sup_7p(&ushnabhojin, Pum, &["uzRaBojizu"]);
+    assert_has_sup_ss(&ushnabhojin, Pum, &["uzRaBojin"]);
+
+    // Rini by 3.1.134 (grahAdi), without an upapada.
+    let grahin = krdanta(&[], &d("graha~^", Kryadi), Krt::Rini);
+    assert_has_sup_1s(&grahin, Pum, &["grAhI"]);
+    assert_has_sup_2s(&grahin, Pum, &["grAhiRam"]);
+    assert_has_sup_6p(&grahin, Pum, &["grAhiRAm"]);
+}
+
+#[test]
+fn han_suppletion() {
+    let han = d("ha\\na~", Adadi);
+
+    // Gn in the weak stem (6.4.98, 7.3.54), lopa of n before Jal (6.4.37).
+    assert_has_tip(&[], &han, Lat, &["hanti"]);
+    assert_has_tas(&[], &han, Lat, &["hataH"]);
+    assert_has_jhi(&[], &han, Lat, &["Gnanti"]);
+    assert_has_sip(&[], &han, Lat, &["haMsi"]);
+    assert_has_mas(&[], &han, Lat, &["hanmaH"]);
+
+    // ja-adesha before hi (6.4.36).
+    assert_has_tip(&[], &han, Lot, &["hantu", "hatAt"]);
+    assert_has_jhi(&[], &han, Lot, &["Gnantu"]);
+    assert_has_sip(&[], &han, Lot, &["jahi", "hatAt"]);
+    assert_has_mip(&[], &han, Lot, &["hanAni"]);
+
+    // vaDa-adesha in lun and ASIrlin (2.4.42 - 2.4.43).
+    assert_has_tip(&[], &han, Lun, &["avaDIt"]);
+    assert_has_jhi(&[], &han, Lun, &["avaDizuH"]);
+    assert_has_tip(&[], &han, AshirLin, &["vaDyAt"]);
+    assert_has_jhi(&[], &han, AshirLin, &["vaDyAsuH"]);
+
+    // vaDa-adesha is optional in the Atmanepada lun (2.4.44).
+    assert_has_ta(&["AN"], &han, Lun, &["Ahata", "AvaDizwa"]);
+    assert_has_ta(&["AN"], &han, AshirLin, &["AvaDizIzwa"]);
+    assert_has_ta_k(&[], &han, Lun, &["aGAni", "avaDi"]);
+
+    // Gha in lit by 7.3.55.
+    assert_has_tip(&[], &han, Lit, &["jaGAna"]);
+    assert_has_jhi(&[], &han, Lit, &["jaGnuH"]);
+}
+
+#[test]
+fn cvi_and_qac_with_kr_and_bhu() {
+    use vidyut_prakriya::args::Taddhita as T;
+    use vidyut_prakriya::args::TaddhitaArtha::AbhutaTadbhava;
+
+    // First derive the stem, then use it as a gati (1.4.61) with kf or BU.
+    let v = Vyakarana::new();
+    let stem = |args: Taddhitanta| -> String {
+        let prakriyas = v.derive_taddhitantas(&args);
+        assert_eq!(prakriyas.len(), 1);
+        prakriyas[0].text()
+    };
+
+    let kr = d("qukf\\Y", Tanadi);
+    let bhu = d("BU", Bhvadi);
+
+    // a -> I (7.4.32)
+    let shukli = stem(artha_taddhitanta("Sukla", AbhutaTadbhava, T::cvi));
+    assert_eq!(shukli, "SuklI");
+    assert_has_tip(&[&shukli], &kr, Lat, &["SuklIkaroti"]);
+    assert_has_tip(&[&shukli], &bhu, Lat, &["SuklIBavati"]);
+    assert_has_krdanta(&[&shukli], &kr, Krt::ktvA, &["SuklIkftya"]);
+
+    // Dirgha for other vowels (7.4.26)
+    let shuci = stem(artha_taddhitanta("Suci", AbhutaTadbhava, T::cvi));
+    assert_eq!(shuci, "SucI");
+    assert_has_tip(&[&shuci], &bhu, Lat, &["SucIBavati"]);
+    let patu = stem(artha_taddhitanta("pawu", AbhutaTadbhava, T::cvi));
+    assert_eq!(patu, "pawU");
+    assert_has_tip(&[&patu], &kr, Lat, &["pawUkaroti"]);
+
+    // qAc (5.4.57)
+    let patapata = stem(taddhitanta("pawat", T::qAc));
+    assert_eq!(patapata, "pawapawA");
+    assert_has_tip(&[&patapata], &kr, Lat, &["pawapawAkaroti"]);
+    assert_has_tip(&[&patapata], &bhu, Lat, &["pawapawABavati"]);
+}
+
+#[test]
+fn lit_with_samprasarana() {
+    // Strong forms keep full grade and take samprasarana only in the abhyasa (6.1.17).
+    // Weak forms take samprasarana throughout (6.1.15) and lengthen by savarna-dirgha (6.1.101).
+    let vac = d("va\\ca~", Adadi);
+    assert_has_tip(&[], &vac, Lit, &["uvAca"]);
+    assert_has_tas(&[], &vac, Lit, &["UcatuH"]);
+    assert_has_jhi(&[], &vac, Lit, &["UcuH"]);
+    assert_has_sip(&[], &vac, Lit, &["uvaciTa", "uvakTa"]);
+    assert_has_thas(&[], &vac, Lit, &["UcaTuH"]);
+    assert_has_tha(&[], &vac, Lit, &["Uca"]);
+    assert_has_mip(&[], &vac, Lit, &["uvAca", "uvaca"]);
+    assert_has_vas(&[], &vac, Lit, &["Uciva"]);
+    assert_has_mas(&[], &vac, Lit, &["Ucima"]);
+
+    let yaj = d("ya\\ja~^", Bhvadi);
+    assert_has_tip(&[], &yaj, Lit, &["iyAja"]);
+    assert_has_tas(&[], &yaj, Lit, &["IjatuH"]);
+    assert_has_jhi(&[], &yaj, Lit, &["IjuH"]);
+    assert_has_sip(&[], &yaj, Lit, &["iyajiTa", "iyazWa"]);
+    assert_has_thas(&[], &yaj, Lit, &["IjaTuH"]);
+    assert_has_tha(&[], &yaj, Lit, &["Ija"]);
+    assert_has_mip(&[], &yaj, Lit, &["iyAja", "iyaja"]);
+    assert_has_vas(&[], &yaj, Lit, &["Ijiva"]);
+    assert_has_mas(&[], &yaj, Lit, &["Ijima"]);
+    assert_has_ta(&[], &yaj, Lit, &["Ije"]);
+    assert_has_aataam(&[], &yaj, Lit, &["IjAte"]);
+    assert_has_jha(&[], &yaj, Lit, &["Ijire"]);
+
+    let vash = d("vaSa~", Adadi);
+    assert_has_tip(&[], &vash, Lit, &["uvASa"]);
+    assert_has_jhi(&[], &vash, Lit, &["USuH"]);
+    assert_has_sip(&[], &vash, Lit, &["uvaSiTa"]);
+
+    let vap = d("quva\\pa~^", Bhvadi);
+    assert_has_tip(&[], &vap, Lit, &["uvApa"]);
+    assert_has_jhi(&[], &vap, Lit, &["UpuH"]);
+    assert_has_sip(&[], &vap, Lit, &["uvapiTa", "uvapTa"]);
+    assert_has_jha(&[], &vap, Lit, &["Upire"]);
+}
+
+#[test]
+fn as_is_us_neuter_stems() {
+    assert_has_sup_1s("manas", Napumsaka, &["manaH"]);
+    assert_has_sup_1p("manas", Napumsaka, &["manAMsi"]);
+    assert_has_sup_3s("manas", Napumsaka, &["manasA"]);
+    assert_has_sup_3p("manas", Napumsaka, &["manoBiH"]);
+
+    // The final `s` of these stems is from uNAdi isi~ and usi~, so it takes zatva (8.3.59).
+    assert_has_sup_1s("havis", Napumsaka, &["haviH"]);
+    assert_has_sup_1p("havis", Napumsaka, &["havIMzi"]);
+    assert_has_sup_3s("havis", Napumsaka, &["havizA"]);
+    assert_has_sup_3p("havis", Napumsaka, &["havirBiH"]);
+
+    assert_has_sup_1s("Ayus", Napumsaka, &["AyuH"]);
+    assert_has_sup_1p("Ayus", Napumsaka, &["AyUMzi"]);
+    assert_has_sup_3s("Ayus", Napumsaka, &["AyuzA"]);
+    assert_has_sup_3p("Ayus", Napumsaka, &["AyurBiH"]);
+
+    assert_has_sup_3s("sarpis", Napumsaka, &["sarpizA"]);
+    assert_has_sup_3s("jyotis", Napumsaka, &["jyotizA"]);
+    assert_has_sup_3s("yajus", Napumsaka, &["yajuzA"]);
+}
+
+#[test]
+fn bru_forms() {
+    let bru = d("brUY", Adadi);
+
+    // For the first five parasmaipada endings, the Aha-Adesha is optional (3.4.84).
+    // Before hal-Adi pit endings, brU takes an Iw-Agama (7.3.93).
+    assert_has_tip(&[], &bru, Lat, &["Aha", "bravIti"]);
+    assert_has_tas(&[], &bru, Lat, &["AhatuH", "brUtaH"]);
+    assert_has_jhi(&[], &bru, Lat, &["AhuH", "bruvanti"]);
+    assert_has_sip(&[], &bru, Lat, &["AtTa", "bravIzi"]);
+    assert_has_thas(&[], &bru, Lat, &["AhaTuH", "brUTaH"]);
+    assert_has_tha(&[], &bru, Lat, &["brUTa"]);
+    assert_has_mip(&[], &bru, Lat, &["bravImi"]);
+    assert_has_vas(&[], &bru, Lat, &["brUvaH"]);
+    assert_has_mas(&[], &bru, Lat, &["brUmaH"]);
+
+    assert_has_ta(&[], &bru, Lat, &["brUte"]);
+    assert_has_jha(&[], &bru, Lat, &["bruvate"]);
+    assert_has_tip(&[], &bru, Lan, &["abravIt"]);
+    assert_has_jhi(&[], &bru, Lan, &["abruvan"]);
+
+    // vac-Adesha in ArdhadhAtuka (2.4.53).
+    assert_has_tip(&[], &bru, Lit, &["uvAca"]);
+    assert_has_jhi(&[], &bru, Lit, &["UcuH"]);
+}
+
+#[test]
+fn i_and_adhi_i() {
+    // yaR-Adesha before ac-Adi apit endings (6.4.81).
+    let i = d("i\\R", Adadi);
+    assert_has_tip(&[], &i, Lat, &["eti"]);
+    assert_has_tas(&[], &i, Lat, &["itaH"]);
+    assert_has_jhi(&[], &i, Lat, &["yanti"]);
+    assert_has_sip(&[], &i, Lat, &["ezi"]);
+    assert_has_thas(&[], &i, Lat, &["iTaH"]);
+    assert_has_tha(&[], &i, Lat, &["iTa"]);
+    assert_has_mip(&[], &i, Lat, &["emi"]);
+    assert_has_vas(&[], &i, Lat, &["ivaH"]);
+    assert_has_mas(&[], &i, Lat, &["imaH"]);
+    assert_has_jhi(&[], &i, Lan, &["Ayan"]);
+    assert_has_tip(&[], &i, Lit, &["iyAya"]);
+    assert_has_tas(&[], &i, Lit, &["IyatuH"]);
+    assert_has_jhi(&[], &i, Lit, &["IyuH"]);
+
+    // iN is always used with aDi and is Atmanepada. Before ac-Adi endings, it takes iyaN (6.4.77).
+    let adhi_i = d("i\\N", Adadi);
+    assert_has_ta(&["aDi"], &adhi_i, Lat, &["aDIte"]);
+    assert_has_aataam(&["aDi"], &adhi_i, Lat, &["aDIyAte"]);
+    assert_has_jha(&["aDi"], &adhi_i, Lat, &["aDIyate"]);
+    assert_has_thaas(&["aDi"], &adhi_i, Lat, &["aDIze"]);
+    assert_has_aathaam(&["aDi"], &adhi_i, Lat, &["aDIyATe"]);
+    assert_has_dhvam(&["aDi"], &adhi_i, Lat, &["aDIDve"]);
+    assert_has_iw(&["aDi"], &adhi_i, Lat, &["aDIye"]);
+    assert_has_vahi(&["aDi"], &adhi_i, Lat, &["aDIvahe"]);
+    assert_has_mahin(&["aDi"], &adhi_i, Lat, &["aDImahe"]);
+    // gAN-Adesha in liw (2.4.49).
+    assert_has_ta(&["aDi"], &adhi_i, Lit, &["aDijage"]);
+}
+
+#[test]
+fn yananta_krdantas() {
+    // yaN-anta dhatus are Atmanepada (1.3.12), so they take SAnac but not Satf.
+    let bobhuya = yan(&d("BU", Bhvadi));
+    assert_has_krdanta(&[], &bobhuya, Krt::SAnac, &["boBUyamAna"]);
+    assert_has_krdanta(&[], &bobhuya, Krt::Satf, &[]);
+    assert_has_krdanta(&[], &bobhuya, Krt::tumun, &["boBUyitum"]);
+    assert_has_krdanta(&[], &bobhuya, Krt::kta, &["boBUyita"]);
+    assert_has_krdanta(&[], &bobhuya, Krt::ktavatu, &["boBUyitavat"]);
+    assert_has_krdanta(&[], &bobhuya, Krt::tavya, &["boBUyitavya"]);
+    assert_has_krdanta(&[], &bobhuya, Krt::ktvA, &["boBUyitvA"]);
+
+    let panipatya = yan(&d("patx~", Bhvadi));
+    assert_has_krdanta(&[], &panipatya, Krt::SAnac, &["panIpatyamAna"]);
+    assert_has_krdanta(&[], &panipatya, Krt::kta, &["panIpatita"]);
+
+    // yaN-luk dhatus are parasmaipada, so they take Satf instead.
+    let bobhu = yan_luk(&d("BU", Bhvadi));
+    assert_has_krdanta(&[], &bobhu, Krt::Satf, &["boBuvat"]);
+    assert_has_krdanta(&[], &bobhu, Krt::SAnac, &[]);
+    assert_has_krdanta(&[], &bobhu, Krt::tumun, &["boBavitum"]);
+}
+
+#[test]
+fn natva_and_shatva_across_boundaries() {
+    // Ratva after an upasarga (8.4.14)
+    let nam = d("Rama~", Bhvadi);
+    assert_has_tip(&["pra"], &nam, Lat, &["praRamati"]);
+    assert_has_tip(&["pari"], &nam, Lat, &["pariRamati"]);
+    assert_has_tip(&["pra"], &d("RI\\Y", Bhvadi), Lat, &["praRayati"]);
+    // Ratva in a vikarana (8.4.15)
+    assert_has_tip(&["pra"], &d("hi\\", Svadi), Lat, &["prahiRoti"]);
+
+    // Ratva in a pratyaya, with and without intervening sounds (8.4.1, 8.4.2)
+    assert_has_krdanta(&[], &d("qukf\\Y", Tanadi), Krt::lyuw, &["karaRa"]);
+    assert_has_krdanta(&[], &d("qukf\\Y", Tanadi), Krt::SAnac, &["kurvARa"]);
+    assert_has_krdanta(&[], &d("kzuBa~\\", Bhvadi), Krt::SAnac, &["kzoBamARa"]);
+    // But, not for kzuBnAti, etc. (8.4.39)
+    assert_has_tip(&[], &d("kzuBa~", Kryadi), Lat, &["kzuBnAti"]);
+
+    // zatva after an upasarga (8.3.65, 8.3.66)
+    assert_has_tip(&["ni"], &d("za\\dx~", Bhvadi), Lat, &["nizIdati"]);
+    assert_has_tip(&["aBi"], &d("zu\\Y", Svadi), Lat, &["aBizuRoti"]);
+    assert_has_tip(&["aDi"], &d("zWA\\", Bhvadi), Lat, &["aDitizWati"]);
+    // zatva across the aw-Agama (8.3.63)
+    assert_has_tip(&["aBi"], &d("zi\\ca~^", Tudadi), Lan, &["aByaziYcat"]);
+}
+
+#[test]
+fn ekac_ii_uu_stri_stems() {
+    // strI takes iyaN (6.4.79), optionally for am and Sas (6.4.80).
+    let stri = nyap("strI");
+    assert_has_sup_1s(&stri, Stri, &["strI"]);
+    assert_has_sup_1p(&stri, Stri, &["striyaH"]);
+    assert_has_sup_2s(&stri, Stri, &["striyam", "strIm"]);
+    assert_has_sup_2p(&stri, Stri, &["striyaH", "strIH"]);
+    assert_has_sup_3s(&stri, Stri, &["striyA"]);
+    assert_has_sup_6p(&stri, Stri, &["strIRAm"]);
+
+    // BrU takes uvaN (6.4.77) and is optionally nadI (1.4.6).
+    assert_has_sup_1d("BrU", Stri, &["BruvO"]);
+    assert_has_sup_2s("BrU", Stri, &["Bruvam"]);
+    assert_has_sup_4s("BrU", Stri, &["BruvE", "Bruve"]);
+    assert_has_sup_6p("BrU", Stri, &["BrURAm", "BruvAm"]);
+
+    // Other monosyllabic stems are kvip-antas and take iyaN and uvaN like a dhatu (6.4.77).
+    assert_has_sup_1s("DI", Stri, &["DIH"]);
+    assert_has_sup_1d("DI", Stri, &["DiyO"]);
+    assert_has_sup_2s("DI", Stri, &["Diyam"]);
+    assert_has_sup_4s("DI", Stri, &["DiyE", "Diye"]);
+    assert_has_sup_6p("DI", Stri, &["DInAm", "DiyAm"]);
+    assert_has_sup_1d("SrI", Stri, &["SriyO"]);
+    assert_has_sup_7s("SrI", Stri, &["SriyAm", "Sriyi"]);
+    assert_has_sup_1d("BU", Stri, &["BuvO"]);
+}
+
+#[test]
+fn nic_can_aorists() {
+    // The abhyasa takes sanvadbhAva (7.4.93) and is lengthened before a light syllable (7.4.94).
+    let kf = nic(&d("qukf\\Y", Tanadi));
+    assert_has_tip(&[], &kf, Lun, &["acIkarat"]);
+    assert_has_jhi(&[], &kf, Lun, &["acIkaran"]);
+    assert_has_ta(&[], &kf, Lun, &["acIkarata"]);
+    assert_has_tip(&[], &nic(&d("janI~\\", Divadi)), Lun, &["ajIjanat"]);
+    assert_has_tip(&[], &nic(&d("ga\\mx~", Bhvadi)), Lun, &["ajIgamat"]);
+    assert_has_mip(&[], &nic(&d("ga\\mx~", Bhvadi)), Lun, &["ajIgamam"]);
+
+    // No lengthening before a heavy syllable.
+    assert_has_tip(&[], &nic(&d("Sikza~\\", Bhvadi)), Lun, &["aSiSikzat"]);
+
+    // 7.4.95 applies to all homonyms of smf and praT.
+    assert_has_tip(&[], &nic(&d("smf\\", Bhvadi)), Lun, &["asasmarat"]);
+    assert_has_tip(&[], &nic(&d("smf\\", Svadi)), Lun, &["asasmarat"]);
+    assert_has_tip(&[], &nic(&d("praTa~\\", Bhvadi)), Lun, &["apapraTat"]);
+}
+
+#[test]
+fn kvasu_declension() {
+    let kf = d("qukf\\Y", Tanadi);
+    assert_has_krdanta(&[], &kf, Krt::kvasu, &["cakfvas"]);
+
+    let cakfvas = krdanta(&[], &kf, Krt::kvasu);
+    assert_has_sup_1s(&cakfvas, Pum, &["cakfvAn"]);
+    assert_has_sup_1p(&cakfvas, Pum, &["cakfvAMsaH"]);
+    assert_has_sup_2p(&cakfvas, Pum, &["cakruzaH"]);
+    assert_has_sup_3s(&cakfvas, Pum, &["cakruzA"]);
+    assert_has_sup_3d(&cakfvas, Pum, &["cakfvadByAm"]);
+    assert_has_sup_7p(&cakfvas, Pum, &["cakfvatsu"]);
+    assert_has_sup_1s(&cakfvas, Stri, &["cakruzI"]);
+    assert_has_sup_1d(&cakfvas, Napumsaka, &["cakruzI"]);
+
+    // iw by 7.2.67, since the stem has one vowel after ettva and abhyAsa-lopa (6.4.120).
+    let pac = d("qupa\\ca~^z", Bhvadi);
+    assert_has_krdanta(&[], &pac, Krt::kvasu, &["pecivas"]);
+    assert_has_krdanta(&[], &d("Sa\\kx~", Svadi), Krt::kvasu, &["Sekivas"]);
+
+    let pecivas = krdanta(&[], &pac, Krt::kvasu);
+    assert_has_sup_1s(&pecivas, Pum, &["pecivAn"]);
+    assert_has_sup_2p(&pecivas, Pum, &["pecuzaH"]);
+    assert_has_sup_3d(&pecivas, Pum, &["pecivadByAm"]);
+    assert_has_sup_1s(&pecivas, Stri, &["pecuzI"]);
+}
+
+#[test]
+fn jagr_shas_daridra_cakas() {
+    // jAgf takes guna before all pratyayas except vi, ciN, Nal, and Nit (7.3.85).
+    let jagf = d("jAgf", Adadi);
+    assert_has_tip(&[], &jagf, Lat, &["jAgarti"]);
+    assert_has_tas(&[], &jagf, Lat, &["jAgftaH"]);
+    assert_has_jhi(&[], &jagf, Lat, &["jAgrati"]);
+    assert_has_sip(&[], &jagf, Lat, &["jAgarzi"]);
+    assert_has_thas(&[], &jagf, Lat, &["jAgfTaH"]);
+    assert_has_tha(&[], &jagf, Lat, &["jAgfTa"]);
+    assert_has_mip(&[], &jagf, Lat, &["jAgarmi"]);
+    assert_has_vas(&[], &jagf, Lat, &["jAgfvaH"]);
+    assert_has_mas(&[], &jagf, Lat, &["jAgfmaH"]);
+    assert_has_jhi(&[], &jagf, Lan, &["ajAgaruH"]);
+    assert_has_tip(&[], &jagf, AshirLin, &["jAgaryAt"]);
+
+    // SAs becomes Siz before kit and Nit pratyayas that start with a consonant (6.4.34).
+    let shas = d("SAsu~", Adadi);
+    assert_has_tip(&[], &shas, Lat, &["SAsti"]);
+    assert_has_tas(&[], &shas, Lat, &["SizwaH"]);
+    assert_has_jhi(&[], &shas, Lat, &["SAsati"]);
+    assert_has_sip(&[], &shas, Lat, &["SAssi"]);
+    assert_has_thas(&[], &shas, Lat, &["SizWaH"]);
+    assert_has_tha(&[], &shas, Lat, &["SizWa"]);
+    assert_has_mip(&[], &shas, Lat, &["SAsmi"]);
+    assert_has_vas(&[], &shas, Lat, &["SizvaH"]);
+    assert_has_mas(&[], &shas, Lat, &["SizmaH"]);
+    assert_has_sip(&[], &shas, Lot, &["SADi", "SizwAt"]);
+
+    // daridrA and cakAs are abhyasta (6.1.6) and so take at for Ji (7.1.4).
+    let daridra = d("daridrA", Adadi);
+    assert_has_tip(&[], &daridra, Lat, &["daridrAti"]);
+    assert_has_tas(&[], &daridra, Lat, &["daridritaH"]);
+    assert_has_jhi(&[], &daridra, Lat, &["daridrati"]);
+    assert_has_jhi(&[], &daridra, Lan, &["adaridruH"]);
+    assert_has_tip(&[], &daridra, VidhiLin, &["daridriyAt"]);
+
+    let cakas = d("cakAsf~", Adadi);
+    assert_has_tip(&[], &cakas, Lat, &["cakAsti"]);
+    assert_has_jhi(&[], &cakas, Lat, &["cakAsati"]);
+    assert_has_jhi(&[], &cakas, Lan, &["acakAsuH"]);
+    assert_has_sip(&[], &cakas, Lot, &["cakADi", "cakAstAt"]);
+}
+
+#[test]
+fn ramul_abhikshnye() {
+    let bhuj = d("Bu\\ja~", Rudhadi);
+    let dfs = d("df\\Si~r", Bhvadi);
+    assert_has_krdanta(&[], &bhuj, Krt::Ramul, &["Bojam"]);
+    assert_has_krdanta(&[], &dfs, Krt::Ramul, &["darSam"]);
+    assert_has_krdanta(&[], &d("smf", Bhvadi), Krt::Ramul, &["smAram"]);
+    assert_has_krdanta(&[], &d("qupa\\ca~^z", Bhvadi), Krt::Ramul, &["pAcam"]);
+
+    // Ramul is lit, so the vowel before it is udAtta (6.1.193).
+    let with_svaras = Vyakarana::builder().use_svaras(true).build();
+    let bhojam = with_svaras.derive_krdantas(&krdanta(&[], &bhuj, Krt::Ramul));
+    assert_eq!(bhojam[0].text(), "Bo/jam");
+
+    // The repeated form (8.1.4) is a vakya of two identical avyayas. Parasavarna is optional
+    // between padas (8.4.59).
+    let v = Vyakarana::new();
+    let amredita = |dhatu: &Dhatu| -> Vec<String> {
+        let pada: Pada = Subanta::avyaya(krdanta(&[], dhatu, Krt::Ramul)).into();
+        let mut ret: Vec<_> = v
+            .derive_vakyas(&[pada.clone(), pada])
+            .iter()
+            .map(|p| p.vakya_text())
+            .collect();
+        ret.sort();
+        ret.dedup();
+        ret
+    };
+    assert_eq!(amredita(&bhuj), vec!["BojaM Bojam", "Bojam Bojam"]);
+    assert_eq!(amredita(&dfs), vec!["darSaM darSam", "darSan darSam"]);
+}
+
+#[test]
+fn avyaya_subantas_take_luk() {
+    // 2.4.82 deletes the sup-pratyaya after an avyaya.
+    let t = Tester::default();
+    let texts = |args: Subanta| -> Vec<String> {
+        t.derive_subantas(&args).iter().map(|p| p.text()).collect()
+    };
+
+    let ca = Pratipadika::avyaya("ca".try_into().unwrap());
+    assert_eq!(texts(Subanta::avyaya(ca)), vec!["ca"]);
+
+    // 1.1.63 blocks num for ugit krdantas (7.1.70).
+    let kf = d("qukf\\Y", Tanadi);
+    let kartum = Subanta::avyaya(krdanta(&[], &kf, Krt::tumun));
+    assert_eq!(texts(kartum), vec!["kartum"]);
+    let kftva = Subanta::avyaya(krdanta(&[], &kf, Krt::ktvA));
+    assert_eq!(texts(kftva), vec!["kftvA"]);
+}
+
+#[test]
+fn ghu_dhatus() {
+    // Weak forms lose their A (6.4.112). DA also becomes Da (8.2.38).
+    let da = d("qudA\\Y", Juhotyadi);
+    assert_has_tip(&[], &da, Lat, &["dadAti"]);
+    assert_has_tas(&[], &da, Lat, &["dattaH"]);
+    assert_has_jhi(&[], &da, Lat, &["dadati"]);
+    assert_has_thas(&[], &da, Lat, &["datTaH"]);
+    assert_has_vas(&[], &da, Lat, &["dadvaH"]);
+    assert_has_ta(&[], &da, Lat, &["datte"]);
+    assert_has_sip(&[], &da, Lot, &["dehi", "dattAt"]);
+
+    let dha = d("quDA\\Y", Juhotyadi);
+    assert_has_tip(&[], &dha, Lat, &["daDAti"]);
+    assert_has_tas(&[], &dha, Lat, &["DattaH"]);
+    assert_has_jhi(&[], &dha, Lat, &["daDati"]);
+    assert_has_thas(&[], &dha, Lat, &["DatTaH"]);
+    assert_has_vas(&[], &dha, Lat, &["daDvaH"]);
+    assert_has_ta(&[], &dha, Lat, &["Datte"]);
+    assert_has_dhvam(&[], &dha, Lat, &["DadDve"]);
+    assert_has_sip(&[], &dha, Lot, &["Dehi", "DattAt"]);
+
+    // kta uses dad (7.4.46) and hi (7.4.42).
+    assert_has_krdanta(&[], &da, Krt::kta, &["datta"]);
+    assert_has_krdanta(&["pra"], &da, Krt::kta, &["pratta"]);
+    assert_has_krdanta(&["ni"], &da, Krt::kta, &["nItta"]);
+    assert_has_krdanta(&[], &da, Krt::ktvA, &["dattvA"]);
+    assert_has_krdanta(&[], &dha, Krt::kta, &["hita"]);
+    assert_has_krdanta(&["ni"], &dha, Krt::kta, &["nihita"]);
+    assert_has_krdanta(&[], &dha, Krt::ktvA, &["hitvA"]);
+    assert_has_krdanta(&[], &d("do\\", Divadi), Krt::kta, &["dita"]);
+    assert_has_krdanta(&[], &d("De\\w", Bhvadi), Krt::kta, &["DIta"]);
+
+    // yat with ettva (6.4.65).
+    assert_has_krdanta(&[], &da, Krt::yat, &["deya"]);
+    assert_has_krdanta(&[], &dha, Krt::yat, &["Deya"]);
+}
+
+#[test]
+fn pronoun_stems_by_linga() {
+    assert_has_sup_1s("idam", Pum, &["ayam"]);
+    assert_has_sup_1p("idam", Pum, &["ime"]);
+    assert_has_sup_3s("idam", Pum, &["anena"]);
+    assert_has_sup_1s("idam", Stri, &["iyam"]);
+    assert_has_sup_1p("idam", Stri, &["imAH"]);
+    assert_has_sup_3s("idam", Stri, &["anayA"]);
+    assert_has_sup_1s("idam", Napumsaka, &["idam"]);
+    assert_has_sup_1p("idam", Napumsaka, &["imAni"]);
+
+    assert_has_sup_1s("adas", Pum, &["asO"]);
+    assert_has_sup_1p("adas", Pum, &["amI"]);
+    assert_has_sup_1s("adas", Stri, &["asO"]);
+    assert_has_sup_1p("adas", Stri, &["amUH"]);
+    assert_has_sup_1s("adas", Napumsaka, &["adaH"]);
+    assert_has_sup_1p("adas", Napumsaka, &["amUni"]);
+
+    assert_has_sup_1s("kim", Pum, &["kaH"]);
+    assert_has_sup_1s("kim", Stri, &["kA"]);
+    assert_has_sup_1s("kim", Napumsaka, &["kim"]);
+    assert_has_sup_1p("kim", Napumsaka, &["kAni"]);
+
+    assert_has_sup_1s("etad", Pum, &["ezaH"]);
+    assert_has_sup_1s("etad", Stri, &["ezA"]);
+    assert_has_sup_1s("etad", Napumsaka, &["etat"]);
+    assert_has_sup_1p("etad", Napumsaka, &["etAni"]);
+}
+
+#[test]
+fn gam_han_jan_khan_nasal_lopa() {
+    // 6.4.37 removes the final nasal of gam and han before a jhalAdi kit.
+    let gam = d("ga\\mx~", Bhvadi);
+    assert_has_krdanta(&[], &gam, Krt::kta, &["gata"]);
+    assert_has_krdanta(&[], &gam, Krt::ktavatu, &["gatavat"]);
+    assert_has_krdanta(&[], &gam, Krt::ktvA, &["gatvA"]);
+    assert_has_krdanta(&[], &gam, Krt::ktin, &["gati"]);
+    assert_has_krdanta(&["A"], &gam, Krt::ktvA, &["Agatya", "Agamya"]);
+    assert_has_tip(&[], &gam, Lat, &["gacCati"]);
+    assert_has_tas(&[], &gam, Lit, &["jagmatuH"]);
+    assert_has_tip(&[], &gam, Lun, &["agamat"]);
+    assert_has_ta(&["sam"], &gam, Lun, &["samagaMsta", "samagata"]);
+
+    let han = d("ha\\na~", Adadi);
+    assert_has_krdanta(&[], &han, Krt::kta, &["hata"]);
+    assert_has_krdanta(&[], &han, Krt::ktvA, &["hatvA"]);
+    assert_has_tas(&[], &han, Lat, &["hataH"]);
+    assert_has_jhi(&[], &han, Lat, &["Gnanti"]);
+    assert_has_tas(&[], &han, Lit, &["jaGnatuH"]);
+
+    // 6.4.42 lengthens jan, san, and Kan instead.
+    let jan = d("janI~\\", Divadi);
+    assert_has_krdanta(&[], &jan, Krt::kta, &["jAta"]);
+    assert_has_krdanta(&[], &jan, Krt::ktin, &["jAti"]);
+    assert_has_ta(&[], &jan, Lat, &["jAyate"]);
+    assert_has_ta(&[], &jan, Lit, &["jajYe"]);
+    assert_has_ta(&[], &jan, Lun, &["ajani", "ajanizwa"]);
+
+    let khan = d("Kanu~^", Bhvadi);
+    assert_has_krdanta(&[], &khan, Krt::kta, &["KAta"]);
+    assert_has_krdanta(&[], &khan, Krt::ktvA, &["KAtvA", "KanitvA"]);
+    assert_has_ta_k(&[], &khan, Lat, &["KAyate", "Kanyate"]);
+    assert_has_tas(&[], &khan, Lit, &["caKnatuH"]);
+    assert_has_tip(&[], &khan, Lun, &["aKAnIt", "aKanIt"]);
+}
+
+#[test]
+fn consonant_stems_before_bh_and_s_endings() {
+    // jaS before Bis and car before su.
+    assert_has_sup_3p("marut", Pum, &["marudBiH"]);
+    assert_has_sup_7p("marut", Pum, &["marutsu"]);
+    assert_has_sup_3p("suhfd", Pum, &["suhfdBiH"]);
+    assert_has_sup_7p("suhfd", Pum, &["suhftsu"]);
+
+    // viS uses 8.2.36 and optionally takes Duw by 8.3.29. zwutva is blocked by 8.4.42.
+    assert_has_sup_3p("viS", Stri, &["viqBiH"]);
+    assert_has_sup_7p("viS", Stri, &["viwsu", "viwtsu"]);
+
+    // kutva and zatva.
+    assert_has_sup_3p("vAc", Stri, &["vAgBiH"]);
+    assert_has_sup_7p("vAc", Stri, &["vAkzu"]);
+
+    // kuk by 8.3.28.
+    assert_has_sup_7p("prAYc", Pum, &["prANkzu", "prANzu"]);
+}
+
+#[test]
+fn bhvadi_shit_substitutions() {
+    // 7.3.78 applies only before a Sit pratyaya.
+    let pa = d("pA\\", Bhvadi);
+    assert_has_tip(&[], &pa, Lat, &["pibati"]);
+    assert_has_tip(&[], &pa, Lan, &["apibat"]);
+    assert_has_tip(&[], &pa, Lrt, &["pAsyati"]);
+    assert_has_tip(&[], &pa, Lit, &["papO"]);
+
+    let stha = d("zWA\\", Bhvadi);
+    assert_has_tip(&[], &stha, Lat, &["tizWati"]);
+    assert_has_tip(&[], &stha, VidhiLin, &["tizWet"]);
+    assert_has_tip(&[], &stha, Lrt, &["sTAsyati"]);
+
+    let ghra = d("GrA\\", Bhvadi);
+    assert_has_tip(&[], &ghra, Lat, &["jiGrati"]);
+    assert_has_tip(&[], &ghra, Lrt, &["GrAsyati"]);
+
+    let dhma = d("DmA\\", Bhvadi);
+    assert_has_tip(&[], &dhma, Lat, &["Damati"]);
+    assert_has_tip(&[], &dhma, Lrt, &["DmAsyati"]);
+
+    let sad = d("za\\dx~", Bhvadi);
+    assert_has_tip(&[], &sad, Lat, &["sIdati"]);
+
+    // 7.3.77 likewise.
+    let gam = d("ga\\mx~", Bhvadi);
+    assert_has_tip(&[], &gam, Lat, &["gacCati"]);
+    assert_has_tip(&[], &gam, Lot, &["gacCatu", "gacCatAt"]);
+    assert_has_tip(&[], &gam, Lrt, &["gamizyati"]);
+    assert_has_tip(&[], &d("ya\\ma~", Bhvadi), Lat, &["yacCati"]);
+    assert_has_tip(&[], &d("izu~", Tudadi), Lat, &["icCati"]);
+}
+
+#[test]
+fn zwran_instrument_nouns() {
+    use vidyut_prakriya::args::Unadi;
+
+    let pa = d("pA\\", Bhvadi);
+    let ni = d("RI\\Y", Bhvadi);
+    assert_has_krdanta(&[], &pa, Unadi::zwran, &["pAtra"]);
+    assert_has_krdanta(&[], &ni, Krt::zwran, &["netra"]);
+    assert_has_krdanta(&[], &ni, Unadi::zwran, &["netra"]);
+
+    // zwran-antas are napumsaka by default.
+    let v = Vyakarana::new();
+    for krdanta in [
+        Krdanta::new(pa.clone(), Unadi::zwran),
+        Krdanta::new(ni.clone(), Krt::zwran),
+    ] {
+        let prakriyas = v.derive_krdantas(&krdanta);
+        let entry = prakriyas[0].as_pratipadika_entry().unwrap();
+        assert_eq!(entry.likely_linga(), Some(Napumsaka));
+    }
+
+    let patra = create_krdanta("pAtra", &[], &pa, Unadi::zwran);
+    assert_has_sup_1s(&patra, Napumsaka, &["pAtram"]);
+    assert_has_sup_1p(&patra, Napumsaka, &["pAtrARi"]);
+    assert_has_sup_7s(&patra, Napumsaka, &["pAtre"]);
+
+    let netra = create_krdanta("netra", &[], &ni, Krt::zwran);
+    assert_has_sup_1s(&netra, Napumsaka, &["netram"]);
+    assert_has_sup_1d(&netra, Napumsaka, &["netre"]);
+    assert_has_sup_1p(&netra, Napumsaka, &["netrARi"]);
+
+    // An explicit linga still takes priority, and the linganushasana doesn't run.
+    assert_has_sup_1s(&netra, Pum, &["netraH"]);
+    let args = Subanta::new(
+        Krdanta::new(ni, Krt::zwran),
+        Pum,
+        Vibhakti::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_subantas(&args);
+    assert_eq!(prakriyas[0].text(), "netraH");
+    for p in &prakriyas {
+        assert!(p
+            .history()
+            .iter()
+            .all(|step| step.rule() != Rule::Linganushasana("90")));
+    }
+}
+
+#[test]
+fn required_krdanta_keeps_its_dhatu() {
+    let ni = d("RI\\Y", Bhvadi);
+
+    // The dhatu has already taken guna, so 6.1.45 shouldn't apply to it again.
+    let neta = create_krdanta("netf", &[], &ni, Krt::tfc);
+    assert_has_sup_1s(&neta, Pum, &["netA"]);
+
+    let netavya = create_krdanta("netavya", &[], &ni, Krt::tavya);
+    assert_has_sup_1s(&netavya, Pum, &["netavyaH"]);
+}
+
+#[test]
+fn shas_weak_stem() {
+    let shas = d("SAsu~", Adadi);
+
+    // 6.4.34 applies before a kit/Nit pratyaya starting with a consonant.
+    assert_has_tip(&[], &shas, Lat, &["SAsti"]);
+    assert_has_tas(&[], &shas, Lat, &["SizwaH"]);
+    assert_has_jhi(&[], &shas, Lat, &["SAsati"]);
+    assert_has_thas(&[], &shas, Lat, &["SizWaH"]);
+    assert_has_mas(&[], &shas, Lat, &["SizmaH"]);
+
+    // 6.4.35 applies before hi.
+    assert_has_tip(&[], &shas, Lot, &["SAstu", "SizwAt"]);
+    assert_has_sip(&[], &shas, Lot, &["SADi", "SizwAt"]);
+    assert_has_thas(&[], &shas, Lot, &["Sizwam"]);
+
+    assert_has_tip(&[], &shas, AshirLin, &["SizyAt"]);
+    assert_has_jhi(&[], &shas, AshirLin, &["SizyAsuH"]);
+    assert_has_tip(&[], &shas, Lan, &["aSAt"]);
+    assert_has_tas(&[], &shas, Lan, &["aSizwAm"]);
+
+    // The Atmanepada root used with AN does not take 6.4.34.
+    let a_shas = d("SAsu~\\", Adadi);
+    assert_has_ta(&["AN"], &a_shas, Lat, &["ASAste"]);
+}
+
+#[test]
+fn saptami_ekavacana_of_i_u_f_stems() {
+    // 7.3.119
+    assert_has_sup_7s("agni", Pum, &["agnO"]);
+    assert_has_sup_7d("agni", Pum, &["agnyoH"]);
+    assert_has_sup_7p("agni", Pum, &["agnizu"]);
+    assert_has_sup_7s("guru", Pum, &["gurO"]);
+    assert_has_sup_7d("guru", Pum, &["gurvoH"]);
+
+    // Feminine i/u stems are optionally nadI by 1.4.6, which allows 7.3.117.
+    assert_has_sup_7s("mati", Stri, &["matO", "matyAm"]);
+    assert_has_sup_7d("mati", Stri, &["matyoH"]);
+    assert_has_sup_7s("Denu", Stri, &["DenO", "DenvAm"]);
+    // 7.3.116
+    assert_has_sup_7s("DenU", Stri, &["DenvAm"]);
+
+    // 7.3.110
+    assert_has_sup_7s("kartf", Pum, &["kartari"]);
+    assert_has_sup_7d("kartf", Pum, &["kartroH"]);
+    assert_has_sup_7p("kartf", Pum, &["kartfzu"]);
+    assert_has_sup_7s("mAtf", Stri, &["mAtari"]);
+
+    // Neuter stems take num by 7.1.73 instead.
+    assert_has_sup_7s("vAri", Napumsaka, &["vAriRi"]);
+    assert_has_sup_7s("maDu", Napumsaka, &["maDuni"]);
+}
+
+#[test]
+fn qak_and_cha_apatya_and_shaishika() {
+    use vidyut_prakriya::args::Taddhita as T;
+    use vidyut_prakriya::args::TaddhitaArtha::TasyaApatyam;
+
+    // Qak for feminine stems (4.1.120 - 4.1.121)
+    assert_has_taddhita(&nyap("kuntI"), T::Qak, &["kOnteya"]);
+    assert_has_taddhita(&nyap("gaNgA"), T::Qak, &["gANgeya"]);
+    assert_has_taddhita(&nyap("vinatA"), T::Qak, &["vEnateya"]);
+
+    // Qak with inaN (4.1.126), which is not removed by 6.4.144.
+    assert_has_artha_taddhita("kalyARI", TasyaApatyam, T::Qak, &["kAlyARineya"]);
+    assert_has_artha_taddhita("jaratI", TasyaApatyam, T::Qak, &["jAratineya"]);
+
+    // Qak with vuk (4.1.125), with vrddhi of the first vowel.
+    assert_has_artha_taddhita("BrU", TasyaApatyam, T::Qak, &["BrOveya"]);
+
+    // Qak for SuBrAdi (4.1.123)
+    assert_has_artha_taddhita("SuBra", TasyaApatyam, T::Qak, &["SOBreya"]);
+
+    // Ca for vrddha stems (4.2.114) and svasf (4.1.143)
+    assert_has_taddhita("SAlA", T::Ca, &["SAlIya"]);
+    assert_has_taddhita("svasf", T::Ca, &["svasrIya"]);
+}
+
+#[test]
+fn ghi_stems_before_nit_sup() {
+    // Guna by 7.3.111, then 6.1.78 for Ne and 6.1.110 for Nasi~ and Nas.
+    assert_has_sup_4s("agni", Pum, &["agnaye"]);
+    assert_has_sup_5s("agni", Pum, &["agneH"]);
+    assert_has_sup_6s("agni", Pum, &["agneH"]);
+    assert_has_sup_4s("guru", Pum, &["gurave"]);
+    assert_has_sup_5s("guru", Pum, &["guroH"]);
+    assert_has_sup_6s("guru", Pum, &["guroH"]);
+
+    // Not ghi by 1.4.7 and 1.4.8.
+    assert_has_sup_4s("saKi", Pum, &["saKye"]);
+    assert_has_sup_5s("saKi", Pum, &["saKyuH"]);
+    assert_has_sup_6s("pati", Pum, &["patyuH"]);
+
+    // Feminine stems are optionally nadI by 1.4.6.
+    assert_has_sup_4s("mati", Stri, &["mataye", "matyE"]);
+    assert_has_sup_5s("mati", Stri, &["mateH", "matyAH"]);
+    assert_has_sup_6s("Denu", Stri, &["DenoH", "DenvAH"]);
+}
+
+#[test]
+fn sandhi_between_multiple_upasargas() {
+    let gam = d("ga\\mx~", Bhvadi);
+    let stha = d("zWA\\", Bhvadi);
+    let hf = d("hf\\Y", Bhvadi);
+    let i = d("i\\R", Adadi);
+
+    // Two upasargas
+    assert_has_tip(&["sam", "AN"], &gam, Lat, &["samAgacCati"]);
+    assert_has_tip(&["pari", "AN"], &gam, Lat, &["paryAgacCati"]);
+    assert_has_tip(&["prati", "ud"], &stha, Lat, &["pratyuttizWati"]);
+    assert_has_tip(&["vi", "ava"], &hf, Lat, &["vyavaharati"]);
+    assert_has_tip(&["nis", "AN"], &gam, Lat, &["nirAgacCati"]);
+    assert_has_tip(&["pari", "AN"], &i, Lat, &["paryEti"]);
+
+    // Three upasargas
+    assert_has_tip(&["aBi", "ud", "AN"], &gam, Lat, &["aByudAgacCati"]);
+    assert_has_tip(&["anu", "ud", "AN"], &gam, Lat, &["anUdAgacCati"]);
+    assert_has_tip(&["sam", "upa", "AN"], &hf, Lat, &["samupAharati"]);
+    assert_has_tip(&["prati", "upa", "AN"], &hf, Lat, &["pratyupAharati"]);
+    assert_has_tip(&["prati", "upa", "AN"], &i, Lat, &["pratyupEti"]);
+}